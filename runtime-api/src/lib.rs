@@ -0,0 +1,61 @@
+//! Runtime API to query the collectibles pallet without decoding raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use collectibles::{Color, IndexerSnapshot};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait CollectiblesApi<AccountId, Balance, Collectible, Hash, Trade>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        Collectible: Codec,
+        Hash: Codec,
+        Trade: Codec,
+    {
+        /// The unique ids of the collectibles owned by `account`
+        fn collectibles_of(account: AccountId) -> Vec<[u8; 16]>;
+        /// The owner of a collectible, `None` if it doesn't exist
+        fn owner_of(collectible_id: [u8; 16]) -> Option<AccountId>;
+        /// Every collectible currently on sale, neither flagged nor past its listing expiry, with the price it can be bought at
+        fn listings() -> Vec<([u8; 16], Balance)>;
+        /// The full collectible record, `None` if it doesn't exist
+        fn details(collectible_id: [u8; 16]) -> Option<Collectible>;
+        /// The records of several collectibles at once, in the same order as requested
+        fn details_many(collectible_ids: Vec<[u8; 16]>) -> Vec<Option<Collectible>>;
+        /// Whether a collectible has been reported stolen
+        fn is_stolen(collectible_id: [u8; 16]) -> bool;
+        /// Whether a collectible was minted by a verified creator
+        fn is_verified(collectible_id: [u8; 16]) -> bool;
+        /// The reason hash of a collectible's moderation flag, `None` if it isn't flagged
+        fn flag_of(collectible_id: [u8; 16]) -> Option<Hash>;
+        /// The hash of a collectible's off-chain content recorded at mint, `None` if it doesn't exist or has none
+        fn content_hash_of(collectible_id: [u8; 16]) -> Option<[u8; 32]>;
+        /// The price of a collectible, `None` if it doesn't exist or isn't on sale
+        fn price_of(collectible_id: [u8; 16]) -> Option<Balance>;
+        /// The amount of collectibles in existence
+        fn total_supply() -> u64;
+        /// The amount of collectibles destroyed so far
+        fn total_burned() -> u64;
+        /// The amount of collectibles owned by `account`
+        fn owned_count(account: AccountId) -> u32;
+        /// The latest trades of `account`, oldest first
+        fn trade_history(account: AccountId) -> Vec<Trade>;
+        /// The amount of collectibles of `color`
+        fn color_supply(color: Color) -> u32;
+        /// Up to `limit` collectibles of `color` after `cursor`, plus the cursor of the next page
+        fn collectibles_of_color_paged(color: Color, cursor: Option<[u8; 16]>, limit: u32) -> (Vec<[u8; 16]>, Option<[u8; 16]>);
+        /// Up to `limit` collectibles of `account` after `cursor`, plus the cursor of the next page
+        fn collectibles_of_paged(
+            account: AccountId,
+            cursor: Option<[u8; 16]>,
+            limit: u32,
+        ) -> (Vec<[u8; 16]>, Option<[u8; 16]>);
+        /// Up to `limit` listings after `cursor`, plus the cursor of the next page
+        fn all_listed_paged(cursor: Option<[u8; 16]>, limit: u32) -> (Vec<([u8; 16], Balance)>, Option<[u8; 16]>);
+        /// Supply, per-color counts, listings, and metadata and content hashes as of the block the API is called at
+        fn snapshot() -> IndexerSnapshot<Balance>;
+    }
+}
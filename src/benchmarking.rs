@@ -0,0 +1,1796 @@
+//! Benchmarks of the collectibles pallet. Every call is measured in its worst case.
+
+use super::*;
+use codec::Decode;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{fungible::{Inspect as _, InspectHold as _, Mutate as _}, fungibles::{Inspect, Mutate}, EnsureOrigin};
+use frame_support::{weights::Weight, PalletId};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::{Bounded, TrailingZeroInput}, Perbill, Permill};
+use sp_std::{vec, vec::Vec};
+
+// Mint a collectible straight into `owner`, skipping the randomness of `create_collectible`
+fn mint_collectible<T: Config<I>, I: 'static>(owner: &T::AccountId, seed: u8) -> [u8; 16] {
+    let collectible_id = [seed; 16];
+    fund_deposit::<T, I>(owner);
+    Pallet::<T, I>::mint(owner, collectible_id, Color::Red).expect("The collectible doesn't exist yet; qed");
+    collectible_id
+}
+
+// Give `who` enough free balance to reserve an item deposit
+fn fund_deposit<T: Config<I>, I: 'static>(who: &T::AccountId) {
+    T::Currency::set_balance(who, T::Currency::minimum_balance() + ItemDeposit::<T, I>::get());
+}
+
+// Give `owner` of a minted collectible enough free balance to fund the deposit of its co-ownership account
+fn fund_co_ownership<T: Config<I>, I: 'static>(owner: &T::AccountId) {
+    T::Currency::set_balance(owner, T::Currency::minimum_balance() * 2u32.into() + ItemDeposit::<T, I>::get());
+}
+
+fn list<T: Config<I>, I: 'static>(owner: &T::AccountId, collectible_id: [u8; 16]) -> BalanceOf<T, I> {
+    let price = T::Currency::minimum_balance() * 10u32.into();
+    Pallet::<T, I>::set_price(RawOrigin::Signed(owner.clone()).into(), collectible_id, price)
+        .expect("The owner can list the collectible; qed");
+    price
+}
+
+// Mint a collectible into `owner` and split it into shares of the asset for seed 1
+fn fractionalized<T: Config<I>, I: 'static>(owner: &T::AccountId) -> Result<[u8; 16], BenchmarkError> {
+    let collectible_id = mint_collectible::<T, I>(owner, 0);
+    let shares = T::Assets::minimum_balance(T::BenchmarkHelper::asset(0)) * 100u32.into();
+    Pallet::<T, I>::fractionalize(RawOrigin::Signed(owner.clone()).into(), collectible_id, T::BenchmarkHelper::asset(1), shares)?;
+    Ok(collectible_id)
+}
+
+// Mint a collectible into `borrower` and offer it as collateral for a loan
+fn requested_loan<T: Config<I>, I: 'static>(borrower: &T::AccountId) -> Result<[u8; 16], BenchmarkError> {
+    let collectible_id = mint_collectible::<T, I>(borrower, 0);
+    let amount = T::Currency::minimum_balance() * 10u32.into();
+    Pallet::<T, I>::request_loan(
+        RawOrigin::Signed(borrower.clone()).into(),
+        collectible_id,
+        amount,
+        amount * 2u32.into(),
+        10u32.into(),
+    )?;
+    Ok(collectible_id)
+}
+
+// Fund the loan against `collectible_id` from `lender`
+fn fund<T: Config<I>, I: 'static>(lender: &T::AccountId, collectible_id: [u8; 16]) -> Result<(), BenchmarkError> {
+    T::Currency::set_balance(lender, T::Currency::minimum_balance() * 100u32.into() + ItemDeposit::<T, I>::get());
+    Pallet::<T, I>::fund_loan(RawOrigin::Signed(lender.clone()).into(), collectible_id)?;
+    Ok(())
+}
+
+// Mint a collectible into `owner` and offer it for rent
+fn listed_for_rent<T: Config<I>, I: 'static>(owner: &T::AccountId) -> Result<[u8; 16], BenchmarkError> {
+    let collectible_id = mint_collectible::<T, I>(owner, 0);
+    Pallet::<T, I>::list_for_rent(RawOrigin::Signed(owner.clone()).into(), collectible_id, T::Currency::minimum_balance(), 10)?;
+    Ok(collectible_id)
+}
+
+// A collectible staked for a while, with a funded reward source so its rewards can be paid
+fn staked<T: Config<I>, I: 'static>(staker: &T::AccountId) -> Result<[u8; 16], BenchmarkError> {
+    let collectible_id = mint_collectible::<T, I>(staker, 0);
+    Pallet::<T, I>::stake(RawOrigin::Signed(staker.clone()).into(), collectible_id)?;
+    T::Currency::set_balance(&T::StakingRewardSource::get(), BalanceOf::<T, I>::max_value() / 2u32.into());
+    frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 10u32.into());
+    Ok(collectible_id)
+}
+
+// Give a collectible a royalty paid to an account of its own
+fn royalty_beneficiary<T: Config<I>, I: 'static>(collectible_id: [u8; 16]) -> T::AccountId {
+    let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+    Royalties::<T, I>::insert(collectible_id, Royalty { beneficiary: beneficiary.clone(), rate: T::MaxRoyalty::get() });
+    beneficiary
+}
+
+fn co_owners<T: Config<I>, I: 'static>() -> BoundedVec<(T::AccountId, u32), T::MaxCoOwners> {
+    let owners: Vec<_> = (0..T::MaxCoOwners::get()).map(|seed| (account("co_owner", seed, 0), 1)).collect();
+    owners.try_into().expect("There are `MaxCoOwners` co-owners; qed")
+}
+
+// Give a collectible `a` attributes with keys and values as long as allowed
+fn with_attributes<T: Config<I>, I: 'static>(collectible_id: [u8; 16], a: u32) {
+    for seed in 0..a {
+        let mut key = seed.to_le_bytes().to_vec();
+        key.resize(T::KeyLimit::get() as usize, 0);
+        let value = vec![0u8; T::ValueLimit::get() as usize];
+        Attributes::<T, I>::insert(collectible_id, BoundedVec::truncate_from(key), BoundedVec::truncate_from(value));
+    }
+}
+
+// Give a collectible metadata of `m` bytes
+fn with_metadata<T: Config<I>, I: 'static>(collectible_id: [u8; 16], m: u32) {
+    if m > 0 {
+        Metadata::<T, I>::insert(collectible_id, BoundedVec::truncate_from(vec![0u8; m as usize]));
+    }
+}
+
+// Give `who` `o` collectibles besides the ones benchmarked, lifting `MaximumOwned` so they can receive more
+fn owning<T: Config<I>, I: 'static>(who: &T::AccountId, o: u32) {
+    MaximumOwned::<T, I>::put(None::<u32>);
+    for seed in 0..o {
+        let mut unique_id = [u8::MAX; 16];
+        unique_id[..4].copy_from_slice(&seed.to_le_bytes());
+        Pallet::<T, I>::import(Collectible::<T, I> {
+            unique_id,
+            price: None,
+            color: Color::Red,
+            owner: who.clone(),
+            creator: who.clone(),
+        })
+        .expect("The collectible doesn't exist yet; qed");
+    }
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_collectible() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_deposit::<T, I>(&caller);
+
+        let royalty = Royalty { beneficiary: caller.clone(), rate: T::MaxRoyalty::get() };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), Some(royalty), Some([1u8; 32]));
+
+        assert_eq!(OwnedCount::<T, I>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn destroy_collectible(a: Linear<0, { T::MaxImportedAttributes::get() }>, m: Linear<0, { T::StringLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        with_attributes::<T, I>(collectible_id, a);
+        with_metadata::<T, I>(collectible_id, m);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert!(!CollectibleMap::<T, I>::contains_key(collectible_id));
+        assert_eq!(Attributes::<T, I>::iter_prefix(collectible_id).count(), 0);
+        assert!(!Metadata::<T, I>::contains_key(collectible_id));
+        assert_eq!(OwnedCount::<T, I>::get(&caller), 0);
+    }
+
+    #[benchmark]
+    fn transfer(o: Linear<0, { Pallet::<T, I>::max_weighed_owned() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        owning::<T, I>(&to, o);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), to.clone(), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to.clone()));
+        assert_eq!(OwnedCount::<T, I>::get(&caller), 0);
+        assert_eq!(OwnedCount::<T, I>::get(&to), o + 1);
+        // The sender got their deposit back, after paying the transfer tax and the royalty, and the recipient holds one
+        assert_eq!(T::Currency::balance(&caller), T::Currency::minimum_balance() * 8u32.into() + ItemDeposit::<T, I>::get());
+        assert_eq!(T::Currency::balance_on_hold(&HoldReason::ItemDeposit.into(), &to), ItemDeposit::<T, I>::get());
+    }
+
+    #[benchmark]
+    fn set_price() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let price = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, price);
+
+        assert_eq!(Pallet::<T, I>::price_of(collectible_id), Some(price));
+    }
+
+    // Delisting a co-owned collectible with the approval that completes it
+    #[benchmark]
+    fn remove_from_market() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        fund_co_ownership::<T, I>(&owner);
+        let owners = co_owners::<T, I>();
+        Pallet::<T, I>::share_ownership(RawOrigin::Signed(owner).into(), collectible_id, owners.clone(), Perbill::one())?;
+        CollectibleMap::<T, I>::mutate(collectible_id, |collectible| {
+            if let Some(collectible) = collectible {
+                collectible.price = Some(T::Currency::minimum_balance());
+            }
+        });
+        let (caller, _) = owners.last().cloned().ok_or(BenchmarkError::Weightless)?;
+        let approvers: Vec<_> = owners.iter().take(owners.len() - 1).map(|(co_owner, _)| co_owner.clone()).collect();
+        DelistingApprovals::<T, I>::insert(
+            collectible_id,
+            (
+                BoundedVec::try_from(approvers).map_err(|_| BenchmarkError::Weightless)?,
+                frame_system::Pallet::<T>::block_number() + T::ApprovalPeriod::get(),
+            ),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::price_of(collectible_id), None);
+        assert!(!DelistingApprovals::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy(o: Linear<0, { Pallet::<T, I>::max_weighed_owned() }>) {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        owning::<T, I>(&buyer, o);
+        let price = list::<T, I>(&seller, collectible_id);
+        // Listing touches the collectible, it can only be bought from the next block on
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&seller, T::Currency::minimum_balance());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+        let charity: T::AccountId = account("charity", 0, 0);
+        T::Currency::set_balance(&charity, T::Currency::minimum_balance());
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        CharityCut::<T, I>::put((charity, Perbill::from_percent(10)));
+        SaleBurn::<T, I>::put(Perbill::from_percent(10));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), collectible_id, price, false, Some(frame_system::Pallet::<T>::block_number()));
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(buyer.clone()));
+        assert_eq!(OwnedCount::<T, I>::get(&seller), 0);
+        assert_eq!(OwnedCount::<T, I>::get(&buyer), o + 1);
+        // The seller got their deposit back along with the proceeds
+        let cut = Perbill::from_percent(10).mul_floor(price);
+        let royalty = T::MaxRoyalty::get().mul_floor(price).min(price - cut - cut);
+        assert_eq!(
+            T::Currency::balance(&seller),
+            T::Currency::minimum_balance() + ItemDeposit::<T, I>::get() + price - cut - cut - royalty
+        );
+    }
+
+    #[benchmark]
+    fn force_transfer() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        let collectible_id = mint_collectible::<T, I>(&from, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, from, to.clone());
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_destroy(
+        a: Linear<0, { T::MaxImportedAttributes::get() }>,
+        m: Linear<0, { T::StringLimit::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // Held in escrow, the worst lock to unwind: the buyer is refunded from the escrow account
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = account("buyer", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+        Pallet::<T, I>::buy_escrowed(RawOrigin::Signed(buyer.clone()).into(), collectible_id, price)?;
+        let balance = T::Currency::balance(&buyer);
+        with_attributes::<T, I>(collectible_id, a);
+        with_metadata::<T, I>(collectible_id, m);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id);
+
+        assert!(!CollectibleMap::<T, I>::contains_key(collectible_id));
+        assert_eq!(Attributes::<T, I>::iter_prefix(collectible_id).count(), 0);
+        assert!(!Escrows::<T, I>::contains_key(collectible_id));
+        assert!(!LockedCollectibles::<T, I>::contains_key(collectible_id));
+        assert_eq!(T::Currency::balance(&buyer), balance + price);
+        // Staked collectibles don't leave their stake behind
+        let staked = staked::<T, I>(&account("staker", 0, 0))?;
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Pallet::<T, I>::force_destroy(origin, staked)?;
+        assert!(!Stakes::<T, I>::contains_key(staked));
+        #[cfg(feature = "try-runtime")]
+        assert!(Pallet::<T, I>::do_try_state().is_ok());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_paused() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, true);
+
+        assert!(Paused::<T, I>::get());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_parameter() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Parameter::MaximumOwned(Some(1)));
+
+        assert_eq!(MaximumOwned::<T, I>::get(), Some(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_stolen() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        list::<T, I>(&owner, collectible_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id);
+
+        assert!(Pallet::<T, I>::is_stolen(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clear_stolen() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        StolenCollectibles::<T, I>::insert(collectible_id, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id);
+
+        assert!(!Pallet::<T, I>::is_stolen(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn flag_item() -> Result<(), BenchmarkError> {
+        let origin = T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, T::Hash::default());
+
+        assert!(Pallet::<T, I>::flag_of(collectible_id).is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clear_flag() -> Result<(), BenchmarkError> {
+        let origin = T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        FlaggedCollectibles::<T, I>::insert(collectible_id, T::Hash::default());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id);
+
+        assert!(Pallet::<T, I>::flag_of(collectible_id).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn verify_creator() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let creator: T::AccountId = account("creator", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, creator.clone());
+
+        assert!(VerifiedCreators::<T, I>::contains_key(creator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unverify_creator() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let creator: T::AccountId = account("creator", 0, 0);
+        VerifiedCreators::<T, I>::insert(&creator, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, creator.clone());
+
+        assert!(!VerifiedCreators::<T, I>::contains_key(creator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_transfers_enabled() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, false);
+
+        assert!(!TransfersEnabled::<T, I>::get());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_price_in_asset() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let asset = T::BenchmarkHelper::asset(0);
+        let price = T::Assets::minimum_balance(asset);
+        // Relisting drops the decline of the previous listing
+        let floor = T::Currency::minimum_balance();
+        Pallet::<T, I>::set_declining_price(RawOrigin::Signed(caller.clone()).into(), collectible_id, floor * 10u32.into(), floor, floor)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, asset, price);
+
+        assert_eq!(Pallet::<T, I>::price_of(collectible_id), Some(price));
+        assert!(!DecliningPrices::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy_with_asset() -> Result<(), BenchmarkError> {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let asset = T::BenchmarkHelper::asset(0);
+        let price = T::Assets::minimum_balance(asset) * 10u32.into();
+        Pallet::<T, I>::set_price_in_asset(RawOrigin::Signed(seller.clone()).into(), collectible_id, asset, price)?;
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Assets::mint_into(asset, &buyer, price * 2u32.into())?;
+        let charity: T::AccountId = account("charity", 0, 0);
+        T::Assets::mint_into(asset, &charity, T::Assets::minimum_balance(asset))?;
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Assets::mint_into(asset, &beneficiary, T::Assets::minimum_balance(asset))?;
+        CharityCut::<T, I>::put((charity, Perbill::from_percent(10)));
+        SaleBurn::<T, I>::put(Perbill::from_percent(10));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), collectible_id, asset, price);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(buyer));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_reference_price() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let price = T::Currency::minimum_balance();
+        // Relisting drops the decline of the previous listing
+        Pallet::<T, I>::set_declining_price(RawOrigin::Signed(caller.clone()).into(), collectible_id, price * 10u32.into(), price, price)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, price);
+
+        assert!(ReferencePriced::<T, I>::contains_key(collectible_id));
+        assert!(!DecliningPrices::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_asset_quotes(q: Linear<1, { T::MaxQuotes::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        list::<T, I>(&caller, collectible_id);
+        let price = T::Currency::minimum_balance();
+        let quotes: AssetQuotesOf<T, I> = (0..q)
+            .map(|seed| (T::BenchmarkHelper::asset(seed), price))
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| BenchmarkError::Stop("Too many quotes"))?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, quotes);
+
+        assert_eq!(AssetQuotes::<T, I>::get(collectible_id).len() as u32, q);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn fractionalize() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let asset = T::BenchmarkHelper::asset(1);
+        let shares = T::Assets::minimum_balance(T::BenchmarkHelper::asset(0)) * 100u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, asset, shares);
+
+        assert!(Fractionalized::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn unify() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund_deposit::<T, I>(&caller);
+        let collectible_id = fractionalized::<T, I>(&owner)?;
+        let (asset, shares) = Fractionalized::<T, I>::get(collectible_id).ok_or(BenchmarkError::Stop("Not fractionalized"))?;
+        // Worst case: the shares ended up in another account, which takes the collectible over
+        T::Assets::transfer(asset, &owner, &caller, shares, frame_support::traits::tokens::Preservation::Expendable)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(caller));
+        assert!(!T::Assets::asset_exists(asset));
+        Ok(())
+    }
+
+    // Opening a buyout as a shareholder, or outbidding someone, who gets their bid back
+    #[benchmark]
+    fn bid_buyout(b: Linear<0, 1>) -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let leading: T::AccountId = account("leading", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = fractionalized::<T, I>(&owner)?;
+        let bid = T::Currency::minimum_balance() * 10u32.into();
+        T::Currency::set_balance(&caller, bid * 4u32.into());
+        let end = frame_system::Pallet::<T>::block_number() + T::BuyoutPeriod::get();
+        if b == 1 {
+            T::Currency::set_balance(&leading, bid * 2u32.into());
+            Buyouts::<T, I>::insert(collectible_id, Buyout::<T, I> { bidder: leading.clone(), bid, end });
+            EndingBuyouts::<T, I>::insert(end + 1u32.into(), collectible_id, ());
+            T::Currency::set_balance(&Pallet::<T, I>::account_id(), T::Currency::minimum_balance() + bid);
+        } else {
+            let asset = T::BenchmarkHelper::asset(1);
+            T::Assets::mint_into(asset, &caller, T::Assets::minimum_balance(asset))?;
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, bid * 2u32.into());
+
+        assert_eq!(Buyouts::<T, I>::get(collectible_id).map(|buyout| buyout.bidder), Some(caller));
+        assert!(b == 0 || !EndingBuyouts::<T, I>::contains_key(end + 1u32.into(), collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_buyout() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let bidder: T::AccountId = whitelisted_caller();
+        fund_deposit::<T, I>(&bidder);
+        let collectible_id = fractionalized::<T, I>(&owner)?;
+        let bid = T::Currency::minimum_balance() * 10u32.into();
+        Buyouts::<T, I>::insert(collectible_id, Buyout::<T, I> { bidder: bidder.clone(), bid, end: Zero::zero() });
+        let due: frame_system::pallet_prelude::BlockNumberFor<T> = 1u32.into();
+        EndingBuyouts::<T, I>::insert(due, collectible_id, ());
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(bidder.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(bidder));
+        assert!(!EndingBuyouts::<T, I>::contains_key(due, collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn redeem_shares() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = whitelisted_caller();
+        let collectible_id = fractionalized::<T, I>(&owner)?;
+        let (asset, shares) = Fractionalized::<T, I>::take(collectible_id).ok_or(BenchmarkError::Stop("Not fractionalized"))?;
+        // Worst case: the last shares are redeemed, so the asset is destroyed
+        let proceeds = T::Currency::minimum_balance() * 10u32.into();
+        BuyoutProceeds::<T, I>::insert(collectible_id, (asset, shares, proceeds));
+        T::Currency::set_balance(&Pallet::<T, I>::account_id(), T::Currency::minimum_balance() + proceeds);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), collectible_id);
+
+        assert!(!BuyoutProceeds::<T, I>::contains_key(collectible_id));
+        assert!(!T::Assets::asset_exists(asset));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_backed_collectible() {
+        let caller: T::AccountId = whitelisted_caller();
+        let backing = T::Currency::minimum_balance() * 10u32.into();
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() + ItemDeposit::<T, I>::get() + backing);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), backing);
+
+        assert_eq!(OwnedCount::<T, I>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn request_loan() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let amount = T::Currency::minimum_balance() * 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, amount, amount * 2u32.into(), 10u32.into());
+
+        assert!(Loans::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn cancel_loan() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = requested_loan::<T, I>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Loans::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn fund_loan() -> Result<(), BenchmarkError> {
+        let borrower: T::AccountId = account("borrower", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = requested_loan::<T, I>(&borrower)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+        T::Currency::set_balance(&borrower, T::Currency::minimum_balance() * 100u32.into());
+        assert!(Pallet::<T, I>::fund_loan(RawOrigin::Signed(borrower).into(), collectible_id).is_err());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(Loans::<T, I>::get(collectible_id).map_or(false, |loan| loan.lender.is_some()));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn repay_loan() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let lender: T::AccountId = account("lender", 0, 0);
+        let collectible_id = requested_loan::<T, I>(&caller)?;
+        fund::<T, I>(&lender, collectible_id)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Loans::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_collateral() -> Result<(), BenchmarkError> {
+        let borrower: T::AccountId = account("borrower", 0, 0);
+        let lender: T::AccountId = whitelisted_caller();
+        let collectible_id = requested_loan::<T, I>(&borrower)?;
+        fund::<T, I>(&lender, collectible_id)?;
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 11u32.into());
+        // Trading restrictions don't keep the lender from collecting
+        TransfersEnabled::<T, I>::put(false);
+        MaximumOwned::<T, I>::put(Some(0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(lender.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(lender));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn list_for_rent() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, T::Currency::minimum_balance(), 10);
+
+        assert!(RentalOffers::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn unlist_for_rent() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!RentalOffers::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn rent() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, 10);
+
+        assert_eq!(Pallet::<T, I>::user_of(collectible_id), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn end_rental() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+        Pallet::<T, I>::rent(RawOrigin::Signed(caller.clone()).into(), collectible_id, 1)?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::RentalPeriod::get() + 1u32.into()
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Rentals::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn offer_rent_to_own() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, T::Currency::minimum_balance() * 10u32.into(), Perbill::from_percent(50));
+
+        assert!(RentToOwnTerms::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn exercise_rent_to_own() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        Pallet::<T, I>::offer_rent_to_own(
+            RawOrigin::Signed(owner).into(),
+            collectible_id,
+            T::Currency::minimum_balance() * 10u32.into(),
+            Perbill::from_percent(50),
+        )?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+        Pallet::<T, I>::rent(RawOrigin::Signed(caller.clone()).into(), collectible_id, 1)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(caller));
+        Ok(())
+    }
+
+    // Renewing a streaming rental is the heaviest way a rental can expire
+    #[benchmark]
+    fn expire_rental() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let renter: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&renter, T::Currency::minimum_balance() * 100u32.into());
+        Pallet::<T, I>::start_rent_stream(
+            RawOrigin::Signed(renter).into(),
+            collectible_id,
+            T::Currency::minimum_balance() * 10u32.into(),
+        )?;
+        let expiry = frame_system::Pallet::<T>::block_number() + T::RentalPeriod::get() + 1u32.into();
+
+        #[block]
+        {
+            Pallet::<T, I>::expire_rental(collectible_id, expiry);
+        }
+
+        assert_eq!(RentStreams::<T, I>::get(collectible_id).map(|stream| stream.periods_left), Some(8));
+        // Renewing from the queue keeps the renewed expiry queued: the stale entry at `expiry` is dropped, and the
+        // renewal at the next expiry queues another one while the queue is processed
+        let renewed = expiry + T::RentalPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(renewed);
+        Pallet::<T, I>::expire_rentals(renewed, Weight::MAX);
+        assert_eq!(RentStreams::<T, I>::get(collectible_id).map(|stream| stream.periods_left), Some(7));
+        assert_eq!(QueuedRentalExpiries::<T, I>::get(), 1);
+        assert_eq!(NextRentalExpiry::<T, I>::get(), Some(renewed + 1u32.into()));
+        Ok(())
+    }
+
+    // Processing a block of the expiry queue, besides the rentals in it
+    #[benchmark]
+    fn rental_expiry_step() {
+        let now = frame_system::Pallet::<T>::block_number();
+        NextRentalExpiry::<T, I>::put(now);
+        QueuedRentalExpiries::<T, I>::put(1);
+        RentalExpiries::<T, I>::insert(now, [0u8; 16], ());
+
+        #[block]
+        {
+            Pallet::<T, I>::expire_rentals(now, Weight::MAX);
+        }
+
+        assert!(!NextRentalExpiry::<T, I>::exists());
+    }
+
+    #[benchmark]
+    fn start_rent_stream() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, T::Currency::minimum_balance() * 10u32.into());
+
+        assert_eq!(Pallet::<T, I>::user_of(collectible_id), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn top_up_rent_stream() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+        Pallet::<T, I>::start_rent_stream(
+            RawOrigin::Signed(caller.clone()).into(),
+            collectible_id,
+            T::Currency::minimum_balance() * 10u32.into(),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, T::Currency::minimum_balance() * 10u32.into());
+
+        assert_eq!(
+            RentStreams::<T, I>::get(collectible_id).map(|stream| stream.held),
+            Some(T::Currency::minimum_balance() * 19u32.into())
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn stop_rent_stream() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = listed_for_rent::<T, I>(&owner)?;
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+        Pallet::<T, I>::start_rent_stream(
+            RawOrigin::Signed(caller.clone()).into(),
+            collectible_id,
+            T::Currency::minimum_balance() * 10u32.into(),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!RentStreams::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn stake() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(Stakes::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn claim_staking_rewards() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = staked::<T, I>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert_eq!(Stakes::<T, I>::get(collectible_id).map(|(_, since)| since), Some(frame_system::Pallet::<T>::block_number()));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unstake() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = staked::<T, I>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Stakes::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deposit_revenue() {
+        let caller: T::AccountId = whitelisted_caller();
+        mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 100u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), T::Currency::minimum_balance() * 10u32.into());
+
+        assert_eq!(RevenuePerItem::<T, I>::get(), T::Currency::minimum_balance() * 10u32.into());
+    }
+
+    #[benchmark]
+    fn claim_revenue(c: Linear<1, { T::MaxDetailsQuery::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectibles: Vec<[u8; 16]> = (0..c)
+            .map(|seed| {
+                let mut collectible_id = [0u8; 16];
+                collectible_id[..4].copy_from_slice(&seed.to_le_bytes());
+                fund_deposit::<T, I>(&caller);
+                Pallet::<T, I>::mint(&caller, collectible_id, Color::Red).expect("The collectible doesn't exist yet; qed")
+            })
+            .collect();
+        let depositor: T::AccountId = account("depositor", 0, 0);
+        T::Currency::set_balance(&depositor, T::Currency::minimum_balance() * (100 * c).into());
+        Pallet::<T, I>::deposit_revenue(RawOrigin::Signed(depositor).into(), T::Currency::minimum_balance() * (10 * c).into())?;
+        let collectibles: BoundedVec<_, T::MaxDetailsQuery> = collectibles.try_into().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectibles);
+
+        assert!(RevenueOwed::<T, I>::get(&caller).is_zero());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_charity_cut() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let charity: T::AccountId = account("charity", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some((charity, Perbill::from_percent(10))));
+
+        assert!(CharityCut::<T, I>::exists());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_transfer_tax() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some((TransferFee::OfLastSale(Perbill::from_percent(5)), FeeRecipient::Creator)));
+
+        assert!(TransferTax::<T, I>::exists());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_royalty() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let royalty = Royalty { beneficiary: caller.clone(), rate: T::MaxRoyalty::get() };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, Some(royalty));
+
+        assert!(Royalties::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn set_royalty_enforcement() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(RoyaltyEnforcement::Block));
+
+        assert!(RoyaltyEnforcementMode::<T, I>::exists());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_royalty_exempt() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let account: T::AccountId = account("custodian", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, account.clone(), true);
+
+        assert!(RoyaltyExempt::<T, I>::contains_key(account));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_set_royalty() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, Some(Royalty { beneficiary, rate: T::MaxRoyalty::get() }));
+
+        assert!(Royalties::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remote_buy() -> Result<(), BenchmarkError> {
+        let origin = T::RemoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let buyer = T::RemoteOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        let seller: T::AccountId = account("seller", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, price);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(buyer));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn export_collectible() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, caller.clone());
+
+        assert!(Exported::<T, I>::contains_key(collectible_id));
+    }
+
+    // Importing to an account other than the exporter's, so the collectible changes hands
+    #[benchmark]
+    fn import_collectible() -> Result<(), BenchmarkError> {
+        let origin = T::BridgeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let exporter: T::AccountId = account("exporter", 0, 0);
+        let owner: T::AccountId = account("owner", 0, 0);
+        fund_deposit::<T, I>(&owner);
+        let collectible_id = mint_collectible::<T, I>(&exporter, 0);
+        Pallet::<T, I>::export_collectible(RawOrigin::Signed(exporter).into(), collectible_id, owner.clone())?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, owner.clone());
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(owner));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, Some(delegate.clone()));
+
+        assert_eq!(Approvals::<T, I>::get(collectible_id), Some(delegate));
+    }
+
+    #[benchmark]
+    fn transfer_from() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        Approvals::<T, I>::insert(collectible_id, caller.clone());
+        T::Currency::set_balance(&owner, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), owner, to.clone(), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to));
+    }
+
+    #[benchmark]
+    fn import_collection(
+        i: Linear<1, { T::MaxDetailsQuery::get() }>,
+        a: Linear<0, { T::MaxImportedAttributes::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let keys: Vec<Vec<u8>> = (0..a)
+            .map(|seed| {
+                let mut key = seed.to_le_bytes().to_vec();
+                key.resize(T::KeyLimit::get() as usize, 0);
+                key
+            })
+            .collect();
+        let nfts: Vec<_> = (0..i).map(|seed| T::BenchmarkHelper::nft(&owner, seed, &keys)).collect();
+        let collection = nfts[0].0.clone();
+        let items: BoundedVec<_, T::MaxDetailsQuery> =
+            nfts.into_iter().map(|(_, item)| item).collect::<Vec<_>>().try_into().map_err(|_| BenchmarkError::Weightless)?;
+        let keys: Vec<BoundedVec<u8, T::KeyLimit>> =
+            keys.into_iter().map(|key| key.try_into()).collect::<Result<_, _>>().map_err(|_| BenchmarkError::Weightless)?;
+        let keys: BoundedVec<_, T::MaxImportedAttributes> = keys.try_into().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collection, items, keys);
+
+        assert_eq!(Pallet::<T, I>::total_supply(), i as u64);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+        let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, to, at);
+
+        assert!(ScheduledTransfers::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_transfer() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::schedule_transfer(RawOrigin::Signed(caller.clone()).into(), collectible_id, to, at)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!ScheduledTransfers::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_scheduled_transfer() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::schedule_transfer(RawOrigin::Signed(owner).into(), collectible_id, to.clone(), at)?;
+
+        #[block]
+        {
+            Pallet::<T, I>::execute_scheduled_transfers(at);
+        }
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_manager() {
+        let caller: T::AccountId = whitelisted_caller();
+        let manager: T::AccountId = account("manager", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, Some(manager.clone()));
+
+        assert_eq!(Managers::<T, I>::get(collectible_id), Some(manager));
+    }
+
+    #[benchmark]
+    fn share_ownership() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        fund_co_ownership::<T, I>(&caller);
+        let owners = co_owners::<T, I>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, owners, Perbill::from_percent(50));
+
+        assert!(CoOwned::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn approve_disposal() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        fund_co_ownership::<T, I>(&owner);
+        let owners = co_owners::<T, I>();
+        Pallet::<T, I>::share_ownership(RawOrigin::Signed(owner).into(), collectible_id, owners.clone(), Perbill::one())?;
+        // Every co-owner but the last one approved already, so the last approval transfers the collectible
+        let (caller, _) = owners.last().cloned().ok_or(BenchmarkError::Weightless)?;
+        let approvers: Vec<_> = owners.iter().take(owners.len() - 1).map(|(co_owner, _)| co_owner.clone()).collect();
+        DisposalApprovals::<T, I>::insert(
+            collectible_id,
+            (to.clone(), BoundedVec::try_from(approvers).map_err(|_| BenchmarkError::Weightless)?),
+        );
+
+        // Co-owners exist, so their share of the deposit can be paid however small
+        for (co_owner, _) in owners.iter() {
+            T::Currency::set_balance(co_owner, T::Currency::minimum_balance());
+        }
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, to.clone());
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to));
+        // The co-owner completing the disposal paid the transfer tax and the royalty, and got the last share of the
+        // deposit funding the co-ownership account
+        let funded = ItemDeposit::<T, I>::get() + T::Currency::minimum_balance();
+        let others: BalanceOf<T, I> = (owners.len() as u32 - 1).into();
+        let share = funded - funded / (others + 1u32.into()) * others;
+        assert_eq!(T::Currency::balance(&caller), T::Currency::minimum_balance() * 8u32.into() + share);
+        assert!(T::Currency::balance(&Pallet::<T, I>::co_ownership_account(collectible_id)).is_zero());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_listing_expiry() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        list::<T, I>(&caller, collectible_id);
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, until);
+
+        assert_eq!(ListingExpiries::<T, I>::get(collectible_id), Some(until));
+    }
+
+    #[benchmark]
+    fn delist_expired() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        list::<T, I>(&owner, collectible_id);
+        let now = frame_system::Pallet::<T>::block_number();
+        ListingExpiries::<T, I>::insert(collectible_id, now);
+        ExpiringListings::<T, I>::insert(now + 1u32.into(), collectible_id, ());
+        frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::None, collectible_id);
+
+        assert_eq!(Pallet::<T, I>::price_of(collectible_id), None);
+        assert!(!ExpiringListings::<T, I>::contains_key(now + 1u32.into(), collectible_id));
+    }
+
+    #[benchmark]
+    fn claim_dev_collectible() -> Result<(), BenchmarkError> {
+        if !T::FaucetEnabled::get() {
+            return Err(BenchmarkError::Weightless);
+        }
+        let who: T::AccountId = account("who", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, who.clone());
+
+        assert_eq!(Pallet::<T, I>::owned_count(&who), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn airdrop() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, recipient);
+
+        assert!(Airdrops::<T, I>::contains_key(collectible_id));
+        assert_eq!(T::Currency::balance(&caller), T::Currency::minimum_balance() * 8u32.into());
+    }
+
+    #[benchmark]
+    fn claim_airdrop() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund_deposit::<T, I>(&caller);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        Pallet::<T, I>::airdrop(RawOrigin::Signed(owner).into(), collectible_id, caller.clone())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_airdrop() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        Pallet::<T, I>::airdrop(RawOrigin::Signed(caller.clone()).into(), collectible_id, recipient)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Airdrops::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_pre_signed() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (signer_public, signer) = T::BenchmarkHelper::signer();
+        let to: T::AccountId = account("to", 0, 0);
+        fund_deposit::<T, I>(&to);
+        let collectible_id = mint_collectible::<T, I>(&signer, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+        let transfer = PreSignedTransfer {
+            collectible: collectible_id,
+            to: to.clone(),
+            nonce: 0,
+            deadline: frame_system::Pallet::<T>::block_number() + 10u32.into(),
+        };
+        let signature = T::BenchmarkHelper::sign(&signer_public, &Pallet::<T, I>::pre_signed_payload(&transfer));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), transfer, signature, signer);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(to));
+    }
+
+    #[benchmark]
+    fn set_price_at() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        list::<T, I>(&caller, collectible_id);
+        let price = T::Currency::minimum_balance();
+        let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, price, at);
+
+        assert_eq!(ScheduledPrices::<T, I>::get(collectible_id), Some((price, at)));
+    }
+
+    #[benchmark]
+    fn set_declining_price() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let floor = T::Currency::minimum_balance();
+        let price = floor * 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, price, floor, floor);
+
+        assert!(DecliningPrices::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn set_relist_cooldown() {
+        let caller: T::AccountId = whitelisted_caller();
+        let cooldown = Some(10u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), cooldown);
+
+        assert_eq!(RelistCooldowns::<T, I>::get(caller), cooldown);
+    }
+
+    #[benchmark]
+    fn write_option() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let price = T::Currency::minimum_balance();
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, price, price * 10u32.into(), expiry);
+
+        assert!(CallOptions::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn buy_option() -> Result<(), BenchmarkError> {
+        let writer: T::AccountId = account("writer", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&writer, 0);
+        let premium = T::Currency::minimum_balance();
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::write_option(
+            RawOrigin::Signed(writer).into(), collectible_id, premium, premium * 10u32.into(), expiry
+        )?;
+        T::Currency::set_balance(&caller, premium * 2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(CallOptions::<T, I>::get(collectible_id).and_then(|option| option.holder), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn exercise_option() -> Result<(), BenchmarkError> {
+        let writer: T::AccountId = account("writer", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&writer, 0);
+        let premium = T::Currency::minimum_balance();
+        let strike = premium * 10u32.into();
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        // The writer pausing trading, before or after writing the option, doesn't stop its exercise
+        Pallet::<T, I>::set_trading_paused(RawOrigin::Signed(writer.clone()).into(), collectible_id, true)?;
+        Pallet::<T, I>::write_option(RawOrigin::Signed(writer.clone()).into(), collectible_id, premium, strike, expiry)?;
+        assert!(Pallet::<T, I>::set_trading_paused(RawOrigin::Signed(writer).into(), collectible_id, true).is_err());
+        T::Currency::set_balance(&caller, strike * 2u32.into());
+        Pallet::<T, I>::buy_option(RawOrigin::Signed(caller.clone()).into(), collectible_id)?;
+        let charity: T::AccountId = account("charity", 0, 0);
+        T::Currency::set_balance(&charity, T::Currency::minimum_balance());
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        CharityCut::<T, I>::put((charity, Perbill::from_percent(10)));
+        SaleBurn::<T, I>::put(Perbill::from_percent(10));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(caller));
+        assert!(!TradingPaused::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_option() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let premium = T::Currency::minimum_balance();
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::write_option(
+            RawOrigin::Signed(caller.clone()).into(), collectible_id, premium, premium * 10u32.into(), expiry
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!CallOptions::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy_escrowed() {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), collectible_id, price);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(buyer));
+        assert!(Escrows::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn release_escrow() -> Result<(), BenchmarkError> {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = account("buyer", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+        Pallet::<T, I>::buy_escrowed(RawOrigin::Signed(buyer).into(), collectible_id, price)?;
+        let charity: T::AccountId = account("charity", 0, 0);
+        T::Currency::set_balance(&charity, T::Currency::minimum_balance());
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        CharityCut::<T, I>::put((charity, Perbill::from_percent(10)));
+        SaleBurn::<T, I>::put(Perbill::from_percent(10));
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + T::EscrowPeriod::get());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Escrows::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn dispute_escrow() -> Result<(), BenchmarkError> {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+        Pallet::<T, I>::buy_escrowed(RawOrigin::Signed(buyer.clone()).into(), collectible_id, price)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer), collectible_id);
+
+        assert!(Escrows::<T, I>::get(collectible_id).map_or(false, |escrow| escrow.disputed));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn resolve_dispute() -> Result<(), BenchmarkError> {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let buyer: T::AccountId = account("buyer", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&seller, 0);
+        let price = list::<T, I>(&seller, collectible_id);
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+        T::Currency::set_balance(&buyer, price * 2u32.into() + ItemDeposit::<T, I>::get());
+        Pallet::<T, I>::buy_escrowed(RawOrigin::Signed(buyer.clone()).into(), collectible_id, price)?;
+        Pallet::<T, I>::dispute_escrow(RawOrigin::Signed(buyer).into(), collectible_id)?;
+        let origin = T::ArbitratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collectible_id, true);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(seller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn gift() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() * 10u32.into());
+        T::Currency::set_balance(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+        TransferTax::<T, I>::put((TransferFee::Flat(T::Currency::minimum_balance()), FeeRecipient::Treasury));
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+        RoyaltyEnforcementMode::<T, I>::put(RoyaltyEnforcement::MinimumRoyalty(T::Currency::minimum_balance()));
+        let claim_deadline = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, recipient, claim_deadline);
+
+        assert!(Gifts::<T, I>::contains_key(collectible_id));
+        assert_eq!(T::Currency::balance(&beneficiary), T::Currency::minimum_balance() * 2u32.into());
+    }
+
+    #[benchmark]
+    fn claim_gift() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        fund_deposit::<T, I>(&caller);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        let claim_deadline = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::gift(RawOrigin::Signed(owner).into(), collectible_id, caller.clone(), claim_deadline)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id);
+
+        assert_eq!(Pallet::<T, I>::owner_of(collectible_id), Some(caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revert_gift() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let collectible_id = mint_collectible::<T, I>(&owner, 0);
+        let claim_deadline = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::gift(RawOrigin::Signed(owner).into(), collectible_id, recipient, claim_deadline)?;
+
+        #[block]
+        {
+            Pallet::<T, I>::revert_expired_gifts(claim_deadline + 1u32.into());
+        }
+
+        assert!(!Gifts::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tip_creator() {
+        let creator: T::AccountId = account("creator", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&creator, 0);
+        let beneficiary = royalty_beneficiary::<T, I>(collectible_id);
+        let amount = T::Currency::minimum_balance() * 10u32.into();
+        T::Currency::set_balance(&caller, amount * 2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, amount);
+
+        assert!(T::Currency::balance(&beneficiary) >= amount);
+    }
+
+    #[benchmark]
+    fn set_name() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() + NameDeposit::<T, I>::get());
+        let name: BoundedVec<u8, T::NameLimit> = vec![0; T::NameLimit::get() as usize]
+            .try_into()
+            .expect("The name is `NameLimit` long; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, name.clone());
+
+        assert_eq!(Names::<T, I>::get(collectible_id).map(|(set, _)| set), Some(name));
+    }
+
+    #[benchmark]
+    fn clear_name() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() + NameDeposit::<T, I>::get());
+        let name: BoundedVec<u8, T::NameLimit> = vec![0; T::NameLimit::get() as usize]
+            .try_into()
+            .expect("The name is `NameLimit` long; qed");
+        Pallet::<T, I>::set_name(RawOrigin::Signed(caller.clone()).into(), collectible_id, name)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id);
+
+        assert!(!Names::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_note() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        T::Currency::set_balance(&caller, T::Currency::minimum_balance() + NoteDeposit::<T, I>::get());
+        let note: BoundedVec<u8, T::NoteLimit> = vec![0; T::NoteLimit::get() as usize]
+            .try_into()
+            .expect("The note is `NoteLimit` long; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), collectible_id, Some(note));
+
+        assert!(Notes::<T, I>::contains_key(caller, collectible_id));
+    }
+
+    #[benchmark]
+    fn set_trading_paused() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, true);
+
+        assert!(TradingPaused::<T, I>::contains_key(collectible_id));
+    }
+
+    #[benchmark]
+    fn set_listing_condition() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        list::<T, I>(&caller, collectible_id);
+        let condition = <T::BuyerQualification as BuyerQualification<T::AccountId>>::Condition::decode(
+            &mut TrailingZeroInput::zeroes()
+        ).map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, Some(condition));
+
+        assert!(ListingConditions::<T, I>::contains_key(collectible_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn allow_holder() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let allowed: Vec<T::AccountId> = (1..T::MaxAllowedHolders::get()).map(|seed| account("holder", seed, 0)).collect();
+        AllowedHolders::<T, I>::insert(collectible_id, BoundedVec::truncate_from(allowed));
+        let who: T::AccountId = account("holder", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, who.clone());
+
+        assert!(AllowedHolders::<T, I>::get(collectible_id).map_or(false, |allowed| allowed.contains(&who)));
+    }
+
+    #[benchmark]
+    fn disallow_holder() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collectible_id = mint_collectible::<T, I>(&caller, 0);
+        let allowed: Vec<T::AccountId> = (0..T::MaxAllowedHolders::get()).map(|seed| account("holder", seed, 0)).collect();
+        AllowedHolders::<T, I>::insert(collectible_id, BoundedVec::truncate_from(allowed));
+        let who: T::AccountId = account("holder", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collectible_id, who.clone());
+
+        assert!(AllowedHolders::<T, I>::get(collectible_id).map_or(false, |allowed| !allowed.contains(&who)));
+    }
+
+    #[benchmark]
+    fn set_reward_quota() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let minter = PalletId(*b"py/quest");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, minter, 10);
+
+        assert_eq!(RewardQuotas::<T, I>::get(minter), 10);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reward() -> Result<(), BenchmarkError> {
+        let minter = PalletId(*b"py/quest");
+        let who: T::AccountId = account("who", 0, 0);
+        RewardQuotas::<T, I>::insert(minter, 2);
+        // Rewards in the same extrinsic get distinct ids
+        let first = <Pallet<T, I> as RewardCollectible<T::AccountId>>::reward(minter, &who, Color::Red)?;
+
+        #[block]
+        {
+            <Pallet<T, I> as RewardCollectible<T::AccountId>>::reward(minter, &who, Color::Red)?;
+        }
+
+        assert!(CollectibleMap::<T, I>::contains_key(first));
+        assert_eq!(Pallet::<T, I>::owned_count(&who), 2);
+        assert_eq!(RewardQuotas::<T, I>::get(minter), 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn snapshot_holders(n: Linear<0, { T::MaxSnapshotHolders::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        for seed in 0..n {
+            let mut collectible_id = [0u8; 16];
+            collectible_id[..4].copy_from_slice(&seed.to_le_bytes());
+            fund_deposit::<T, I>(&caller);
+            Pallet::<T, I>::mint(&caller, collectible_id, Color::Red).expect("The collectible doesn't exist yet; qed");
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert!(HolderSnapshots::<T, I>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_default_royalty() {
+        let caller: T::AccountId = whitelisted_caller();
+        let royalty = Royalty { beneficiary: account("beneficiary", 0, 0), rate: T::MaxRoyalty::get() };
+        RoyaltyRange::<T, I>::put((Permill::from_percent(0), T::MaxRoyalty::get()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), Some(royalty));
+
+        assert!(DefaultRoyalties::<T, I>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn set_royalty_range() -> Result<(), BenchmarkError> {
+        let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let range = (Permill::from_percent(0), T::MaxRoyalty::get());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(range));
+
+        assert_eq!(RoyaltyRange::<T, I>::get(), Some(range));
+        Ok(())
+    }
+}
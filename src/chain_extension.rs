@@ -0,0 +1,78 @@
+//! Chain extension exposing the collectibles to `pallet_contracts` smart contracts, e.g. on-chain games written in ink!.
+//! Contracts act on the collectibles owned by their own account: they mint to it and transfer from it.
+
+use super::*;
+use codec::Encode;
+use frame_support::pallet_prelude::*;
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, RegisteredChainExtension, Result, RetVal,
+};
+
+/// Mints a collectible to the contract. Output: its `[u8; 16]` id
+pub const MINT: u16 = 1;
+/// Transfers a collectible of the contract. Input: `(AccountId, [u8; 16])`, the destination and the collectible id
+pub const TRANSFER: u16 = 2;
+/// Input: a `[u8; 16]` collectible id. Output: `Option<AccountId>`, its owner
+pub const OWNER_OF: u16 = 3;
+/// Input: a `[u8; 16]` collectible id. Output: `Option<Balance>`, its price if it's on sale
+pub const PRICE_OF: u16 = 4;
+
+/// The collectibles chain extension, to add to the runtime's `pallet_contracts::Config::ChainExtension`. Its functions
+/// fail with the pallet's errors, reverting the contract call
+pub struct CollectiblesExtension<I = ()>(PhantomData<I>);
+
+impl<I> Default for CollectiblesExtension<I>{
+    fn default() -> Self{
+        Self(PhantomData)
+    }
+}
+
+impl<T, I: 'static> ChainExtension<T> for CollectiblesExtension<I>
+where
+    T: pallet_contracts::Config + Config<I>,
+{
+    fn call<E: Ext<T = T>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal>{
+        let mut env = env.buf_in_buf_out();
+        match env.func_id(){
+            MINT => {
+                env.charge_weight(<T as Config<I>>::WeightInfo::create_collectible())?;
+                let contract = env.ext().address().clone();
+                Pallet::<T, I>::ensure_not_paused()?;
+                let (unique_id, color) = Pallet::<T, I>::gen_unique_id();
+                let collectible_id = Pallet::<T, I>::mint(&contract, unique_id, color)?;
+                env.write(&collectible_id.encode(), false, None)?;
+            },
+            TRANSFER => {
+                env.charge_weight(<T as Config<I>>::WeightInfo::transfer_from())?;
+                let (to, collectible_id): (T::AccountId, [u8; 16]) = env.read_as()?;
+                let contract = env.ext().address().clone();
+                <Pallet<T, I> as Erc721<T::AccountId>>::transfer_from(
+                    &contract,
+                    &contract,
+                    &to,
+                    Pallet::<T, I>::token_id(collectible_id),
+                )?;
+            },
+            OWNER_OF => {
+                env.charge_weight(T::DbWeight::get().reads(1))?;
+                let collectible_id: [u8; 16] = env.read_as()?;
+                env.write(&Pallet::<T, I>::owner_of(collectible_id).encode(), false, None)?;
+            },
+            PRICE_OF => {
+                // The collectible, its price unit and the oracle rate
+                env.charge_weight(T::DbWeight::get().reads(3))?;
+                let collectible_id: [u8; 16] = env.read_as()?;
+                env.write(&Pallet::<T, I>::price_of(collectible_id).encode(), false, None)?;
+            },
+            _ => return Err(DispatchError::Other("Unknown collectibles chain extension function")),
+        }
+        Ok(RetVal::Converging(0))
+    }
+}
+
+impl<T, I: 'static> RegisteredChainExtension<T> for CollectiblesExtension<I>
+where
+    T: pallet_contracts::Config + Config<I>,
+{
+    const ID: u16 = 0x0c01;
+}
@@ -0,0 +1,125 @@
+//! Transaction extension waiving the fees of claims: new users receiving their first collectible often have no balance
+//! to pay fees with. It wraps the runtime's fee-charging extension, which still applies to any other call, e.g.
+//! `SkipFeelessClaims<Runtime, pallet_transaction_payment::ChargeTransactionPayment<Runtime>>` in place of
+//! `ChargeTransactionPayment<Runtime>` in the runtime's `SignedExtra`.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::IsSubType,
+    CloneNoBound, EqNoBound, PartialEqNoBound,
+};
+use sp_runtime::traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension};
+use sp_std::{fmt, vec::Vec};
+
+/// Skips the wrapped extension for the calls `Pallet::is_feeless` accepts. Keeps its identifier, so clients build
+/// transactions as they would for the wrapped extension
+#[derive(CloneNoBound, EqNoBound, PartialEqNoBound, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct SkipFeelessClaims<T, S, I = ()>(pub S, #[codec(skip)] PhantomData<(T, I)>);
+
+impl<T, S, I> SkipFeelessClaims<T, S, I>{
+    pub fn new(inner: S) -> Self{
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, S, I> From<S> for SkipFeelessClaims<T, S, I>{
+    fn from(inner: S) -> Self{
+        Self::new(inner)
+    }
+}
+
+impl<T, S: fmt::Debug, I> fmt::Debug for SkipFeelessClaims<T, S, I>{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "SkipFeelessClaims<{:?}>", self.0)
+    }
+}
+
+impl<T, S, I> SignedExtension for SkipFeelessClaims<T, S, I>
+where
+    T: Config<I> + Send + Sync,
+    I: 'static + Send + Sync,
+    S: SignedExtension<AccountId = T::AccountId, Call = <T as frame_system::Config>::RuntimeCall>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+    const IDENTIFIER: &'static str = S::IDENTIFIER;
+    type AccountId = T::AccountId;
+    type Call = S::Call;
+    type AdditionalSigned = S::AdditionalSigned;
+    // `None` if the wrapped extension was skipped
+    type Pre = Option<S::Pre>;
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError>{
+        self.0.additional_signed()
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity{
+        if Self::is_feeless(who, call){
+            Ok(ValidTransaction::default())
+        }
+        else{
+            self.0.validate(who, call, info, len)
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError>{
+        if Self::is_feeless(who, call){
+            Ok(None)
+        }
+        else{
+            Ok(Some(self.0.pre_dispatch(who, call, info, len)?))
+        }
+    }
+
+    fn validate_unsigned(
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity{
+        S::validate_unsigned(call, info, len)
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError>{
+        match pre{
+            Some(Some(pre)) => S::post_dispatch(Some(pre), info, post_info, len, result),
+            Some(None) => Ok(()),
+            None => S::post_dispatch(None, info, post_info, len, result),
+        }
+    }
+
+    fn metadata() -> Vec<sp_runtime::traits::SignedExtensionMetadata>{
+        S::metadata()
+    }
+}
+
+impl<T, S, I> SkipFeelessClaims<T, S, I>
+where
+    T: Config<I>,
+    I: 'static,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+    fn is_feeless(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool{
+        call.is_sub_type().map_or(false, |call| Pallet::<T, I>::is_feeless(who, call))
+    }
+}
@@ -2,27 +2,293 @@
 
 pub use pallet::*;
 
-#[frame_support::pallet(dev_mode)]
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(feature = "contracts")]
+pub mod chain_extension;
+pub mod feeless;
+
+#[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
-        pallet_prelude::*, traits::{Currency, Get, Randomness}
+        defensive,
+        pallet_prelude::*,
+        storage::with_storage_layer,
+        traits::{
+            fungible, fungibles, nonfungibles, nonfungibles_v2::{self, Inspect as _, Transfer as _},
+            tokens::{Fortitude, Locker, Precision, Preservation, Restriction}, Contains,
+            LockIdentifier,
+            DefensiveSaturating,
+            PalletInfoAccess,
+            Get, Randomness
+        },
+        PalletId
+    };
+    use frame_system::{
+        offchain::{SendTransactionTypes, SubmitTransaction},
+        pallet_prelude::*,
+    };
+    use sp_runtime::{
+        helpers_128bit::multiply_by_rational_with_rounding,
+        offchain::storage::StorageValueRef,
+        traits::{AccountIdConversion, Convert, Hash, IdentifyAccount, One, Saturating, Verify, Zero},
+        Perbill, Permill, Rounding, SaturatedConversion
     };
-    use frame_system::pallet_prelude::*;
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
+    use sp_std::vec::Vec;
+    use crate::WeightInfo;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+    /// The most blocks of due buyouts and listings the offchain worker reads in one run, catching up over several runs
+    /// after missing some
+    const MAX_SCANNED_BLOCKS: u32 = 64;
+
+    /// The most collectibles a recipient is weighed as owning already, when `MaximumOwned` sets no lower cap
+    pub(crate) const MAX_WEIGHED_OWNED: u32 = 1_000;
 
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        type Currency: Currency<Self::AccountId>;
+    pub trait Config<I: 'static = ()>: frame_system::Config + SendTransactionTypes<Call<Self, I>> {
+        type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason, the pallet holds funds for its own `HoldReason`s
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Assets that collectibles can be listed in besides `Currency`, e.g. stablecoins. Shares of fractionalized
+        /// collectibles are created here too
+        type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self, I>>
+            + fungibles::Create<Self::AccountId>
+            + fungibles::Destroy<Self::AccountId>;
+        /// Tells whether accounts have a judged identity, for `MintersNeedIdentity` and `BuyersNeedIdentity`
+        type Identity: IdentityVerifier<Self::AccountId>;
+        /// Checks buyers against the conditions sellers put on their listings, e.g. owning an item of a collection
+        type BuyerQualification: BuyerQualification<Self::AccountId>;
+        /// Consulted by every transfer and purchase, so regulated deployments can enforce KYC or jurisdiction rules
+        type TransferFilter: CheckTransfer<Self::AccountId, [u8; 16]>;
+        /// Converts prices set in a reference unit, e.g. USD, into `Currency`
+        type PriceOracle: PriceOracle<BalanceOf<Self, I>>;
         type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+        /// Origin allowed to act on any collectible regardless of its owner, e.g. governance
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to flag collectibles referencing illegal or abusive content
+        type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin of purchases initiated from other chains through XCM `Transact`, resolving to the local account that
+        /// pays and owns the collectible, e.g. `EnsureXcm` combined with a location to account conversion
+        type RemoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+        /// Origin vouching that a collectible exported to Asset Hub has been burned there and can be imported back,
+        /// e.g. Asset Hub's location through `EnsureXcm`
+        type BridgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin deciding disputed escrowed sales, releasing the price to the seller or refunding the buyer
+        type ArbitratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Default cap of the collectibles an account can own, `None` meaning no cap. Governance can change it through `set_parameter`
+        #[pallet::constant]
+        type MaximumOwned: Get<Option<u32>>;
+
+        /// Whether to index the collectibles of each account in `OwnerOfCollectibles` and enforce `MaximumOwned`.
+        /// Without the index, the owner stored in each collectible is the only record of ownership and the per-account
+        /// queries (`collectibles_of`, `collectibles_of_paged`, `ColorWeightedVotingPower`) find nothing.
+        /// Switching it on a live chain needs `migrations::ownership_index::RebuildOwnershipIndex` in the same upgrade
+        #[pallet::constant]
+        type EnumerateOwnership: Get<bool>;
+
+        /// The maximum amount of collectibles that `details_many` resolves in a single query
+        #[pallet::constant]
+        type MaxDetailsQuery: Get<u32>;
+
+        /// The maximum amount of alternative asset quotes a listing can have
+        #[pallet::constant]
+        type MaxQuotes: Get<u32>;
+
+        /// Default deposit held from the owner of each collectible to pay for its storage. When the collectible changes
+        /// hands, the previous owner gets theirs back and the new owner holds it in turn, failing the transfer if they
+        /// can't. Governance can change it through `set_parameter`
+        #[pallet::constant]
+        type ItemDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The pallet's id, used to derive the account administering the assets it creates and keeping buyout funds
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The unit rentals are priced and measured in
+        #[pallet::constant]
+        type RentalPeriod: Get<BlockNumberFor<Self>>;
+
+        /// How long a buyout of a fractionalized collectible stays open to higher bids, restarting on every bid
+        #[pallet::constant]
+        type BuyoutPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum amount of co-owners a collectible can have
+        #[pallet::constant]
+        type MaxCoOwners: Get<u32>;
+
+        /// Whether `claim_dev_collectible` hands out sample collectibles. Meant for test networks only
+        #[pallet::constant]
+        type FaucetEnabled: Get<bool>;
+
+        /// How long an account waits between faucet claims
+        #[pallet::constant]
+        type FaucetCooldown: Get<BlockNumberFor<Self>>;
+
+        /// The maximum amount of faucet claims per block
+        #[pallet::constant]
+        type MaxFaucetClaimsPerBlock: Get<u32>;
+
+        /// The maximum amount of unsigned transactions the offchain worker submits per block
+        #[pallet::constant]
+        type MaxUnsignedPerBlock: Get<u32>;
+
+        /// How long buyers of escrowed sales can dispute them once the collectible is delivered
+        #[pallet::constant]
+        type EscrowPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The longest a listing can be bought for. Listings expire after it unless their owner sets an earlier expiry
+        #[pallet::constant]
+        type MaxListingDuration: Get<BlockNumberFor<Self>>;
+
+        /// How long co-owners have to approve a listing once the first of them approves it
+        #[pallet::constant]
+        type ApprovalPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum amount of scheduled transfers that can execute in the same block
+        #[pallet::constant]
+        type MaxScheduledTransfers: Get<u32>;
+
+        /// The maximum amount of collectibles `snapshot_holders` can record
+        #[pallet::constant]
+        type MaxSnapshotHolders: Get<u32>;
+
+        /// The maximum amount of recent trades kept for each account
+        #[pallet::constant]
+        type MaxTradeHistory: Get<u32>;
+
+        /// The maximum amount of accounts a restricted collectible can be held by
+        #[pallet::constant]
+        type MaxAllowedHolders: Get<u32>;
+
+        /// The maximum amount of unclaimed gifts that can revert to their senders in the same block
+        #[pallet::constant]
+        type MaxGiftExpiries: Get<u32>;
+
+        /// The account staking rewards are paid from, e.g. a treasury
+        type StakingRewardSource: Get<Self::AccountId>;
+
+        /// Reward accrued by each staked collectible per block, before its color multiplier
+        #[pallet::constant]
+        type StakingRewardPerBlock: Get<BalanceOf<Self, I>>;
+
+        /// Multiplies the staking reward of a collectible depending on its color, so rarer colors can earn more
+        type StakingMultiplier: Convert<Color, u32>;
+
+        /// The treasury account, which transfer taxes can be routed to
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// The highest royalty a creator can set, so secondary sales of collectibles already bought can't be bricked
+        #[pallet::constant]
+        type MaxRoyalty: Get<Permill>;
+
+        /// The `pallet_nfts` instance collections can be imported from through `import_collection`
+        type Nfts: nonfungibles_v2::Inspect<Self::AccountId, CollectionId = Self::NftsCollectionId, ItemId = Self::NftsItemId>
+            + nonfungibles_v2::Transfer<Self::AccountId>;
+        type NftsCollectionId: Parameter + MaxEncodedLen;
+        type NftsItemId: Parameter + MaxEncodedLen;
+
+        /// The maximum length of a collectible's metadata
+        #[pallet::constant]
+        type StringLimit: Get<u32>;
+
+        /// The maximum length of an attribute key
+        #[pallet::constant]
+        type KeyLimit: Get<u32>;
+
+        /// The maximum length of an attribute value
+        #[pallet::constant]
+        type ValueLimit: Get<u32>;
+
+        /// The maximum amount of attribute keys `import_collection` copies for each item
+        #[pallet::constant]
+        type MaxImportedAttributes: Get<u32>;
+
+        /// The maximum length of a collectible's name
+        #[pallet::constant]
+        type NameLimit: Get<u32>;
+
+        /// Default amount held from the owner of a named collectible while it keeps its name. Governance can change it
+        /// through `set_parameter`
+        #[pallet::constant]
+        type NameDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Whether two collectibles can't share a name
+        #[pallet::constant]
+        type UniqueNames: Get<bool>;
+
+        /// Whether collectibles keep their name when they change hands, with the new owner holding the deposit in turn
+        #[pallet::constant]
+        type KeepNamesOnTransfer: Get<bool>;
+
+        /// The maximum length of an owner's note on a collectible
+        #[pallet::constant]
+        type NoteLimit: Get<u32>;
+
+        /// Default amount held from an owner for each note they keep on their collectibles. Governance can change it
+        /// through `set_parameter`
+        #[pallet::constant]
+        type NoteDeposit: Get<BalanceOf<Self, I>>;
 
+        /// Prefix of the token URIs served through `Erc721`, followed by the hex encoded collectible id
         #[pallet::constant]
-        type MaximumOwned: Get<u32>;
+        type BaseTokenUri: Get<Vec<u8>>;
+
+        /// The signature owners pre-authorize sponsored transfers with, e.g. `sp_runtime::MultiSignature`
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// The public key `OffchainSignature`s are verified against, identifying the signing account
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Weights of the pallet calls
+        type WeightInfo: WeightInfo;
+
+        /// Provides an asset the benchmarks can list collectibles in
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            AssetIdOf<Self, I>,
+            Self::AccountId,
+            Self::NftsCollectionId,
+            Self::NftsItemId,
+            Self::OffchainPublic,
+            Self::OffchainSignature
+        >;
     }
 
-    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<AssetId, AccountId, NftsCollectionId, NftsItemId, Public, Signature>{
+        /// A distinct asset for each `seed`. The one for seed 0 must exist, so accounts can be minted into it, and the
+        /// others must not, so they can be created
+        fn asset(seed: u32) -> AssetId;
+        /// Mint a distinct `Nfts` item for each `seed` to `owner`, with metadata of `StringLimit` bytes and an attribute
+        /// of `ValueLimit` bytes under each of `keys`. The pallet account must be able to receive it
+        fn nft(owner: &AccountId, seed: u32, keys: &[Vec<u8>]) -> (NftsCollectionId, NftsItemId);
+        /// A key able to sign, and the account it identifies
+        fn signer() -> (Public, AccountId);
+        /// Sign `message` with `signer`
+        fn sign(signer: &Public, message: &[u8]) -> Signature;
+    }
+
+    #[derive(
+        Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen,
+        frame_support::Serialize, frame_support::Deserialize
+    )]
+    #[serde(crate = "frame_support::serde")]
     pub enum Color {
         Red,
         Yellow,
@@ -30,295 +296,5754 @@ pub mod pallet {
         Green
     }
 
-    type BalanceOf<T> =
-    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    /// What holds a collectible locked. Only what locked it can unlock it
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum LockReason {
+        /// Another pallet, under its own identifier
+        External(LockIdentifier),
+        Fractionalized,
+        Loan,
+        Rental,
+        Staked,
+        /// Burned through the `nonfungibles` interface, e.g. when sent back over XCM
+        Parked,
+        Exported,
+        ScheduledTransfer,
+        CallOption,
+        Escrow,
+        Gift,
+        Airdrop,
+    }
+
+    /// Reasons for the pallet to hold funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// The storage deposit of a collectible
+        ItemDeposit,
+        /// Funds a renter set aside to pay the upcoming periods of a streaming rental
+        RentStream,
+        /// The deposit of a collectible's name
+        NameDeposit,
+        /// The deposit of an owner's note on a collectible
+        NoteDeposit,
+    }
+
+    pub type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type AssetIdOf<T, I = ()> =
+    <<T as Config<I>>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+    pub type AssetQuotesOf<T, I = ()> = BoundedVec<(AssetIdOf<T, I>, BalanceOf<T, I>), <T as Config<I>>::MaxQuotes>;
+
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Collectible<T: Config<I>, I: 'static = ()> {
+        // Unsigned integers of 16 bytes to represent a unique identifier
+        pub unique_id: [u8; 16],
+        // `None` assumes not for sale
+        pub price: Option<BalanceOf<T, I>>,
+        pub color: Color,
+        pub owner: T::AccountId,
+        // The account that minted the collectible
+        pub creator: T::AccountId,
+    }
+
+    /// Runtime parameters that governance can tune through `set_parameter`
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Parameter<Balance> {
+        /// The amount of collectibles an account can own, `None` meaning no cap
+        MaximumOwned(Option<u32>),
+        /// The part of every sale price that is burned
+        SaleBurn(Perbill),
+        /// Whether minters must have a judged identity
+        MintersNeedIdentity(bool),
+        /// Whether buyers must have a judged identity
+        BuyersNeedIdentity(bool),
+        /// The deposit held for each collectible. Deposits held already keep their amount
+        ItemDeposit(Balance),
+        /// The deposit held for each named collectible. Deposits held already keep their amount
+        NameDeposit(Balance),
+        /// The deposit held for each note. Deposits held already keep their amount
+        NoteDeposit(Balance),
+    }
+
+    /// How the tax charged on plain transfers is computed
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum TransferFee<Balance> {
+        /// A fixed amount
+        Flat(Balance),
+        /// A part of the last price the collectible was sold for in the native currency. Collectibles that were never
+        /// sold that way are transferred tax free
+        OfLastSale(Perbill),
+    }
+
+    /// Who the transfer tax goes to
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum FeeRecipient {
+        /// The creator of the collectible transferred
+        Creator,
+        /// The `TreasuryAccount`
+        Treasury,
+    }
+
+    /// The royalty paid to the creator side on every sale of a collectible
+    #[derive(
+        Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+        frame_support::Serialize, frame_support::Deserialize
+    )]
+    #[serde(crate = "frame_support::serde")]
+    pub struct Royalty<AccountId> {
+        pub beneficiary: AccountId,
+        // The part of the sale price paid to the beneficiary
+        pub rate: Permill,
+    }
+
+    /// How royalties are protected from trades settled off-market through plain transfers
+    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RoyaltyEnforcement<Balance> {
+        /// Collectibles with a royalty can only be transferred to exempt accounts
+        Block,
+        /// Transferring a collectible with a royalty to an account that isn't exempt pays this amount to its beneficiary
+        MinimumRoyalty(Balance),
+    }
+
+    /// The leading bid of a buyout of a fractionalized collectible
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Buyout<T: Config<I>, I: 'static = ()> {
+        pub bidder: T::AccountId,
+        // Kept by the pallet account until the bid is outbid or the buyout settled
+        pub bid: BalanceOf<T, I>,
+        // Last block in which the bid can be outbid
+        pub end: BlockNumberFor<T>,
+    }
+
+    /// A loan collateralized by a collectible
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Loan<T: Config<I>, I: 'static = ()> {
+        pub borrower: T::AccountId,
+        pub amount: BalanceOf<T, I>,
+        // What the borrower must pay back to keep the collectible
+        pub repayment: BalanceOf<T, I>,
+        pub duration: BlockNumberFor<T>,
+        // The lender and the repayment deadline, `None` until the loan is funded
+        pub lender: Option<(T::AccountId, BlockNumberFor<T>)>,
+    }
+
+    /// A rental paid period by period from funds held from the renter
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct RentStream<T: Config<I>, I: 'static = ()> {
+        pub price_per_period: BalanceOf<T, I>,
+        // What the renter still holds for the upcoming periods
+        pub held: BalanceOf<T, I>,
+        // Periods the rental can still be renewed for, as allowed by the rental offer
+        pub periods_left: u32,
+    }
+
+    /// The joint owners of a co-owned collectible, which is held by its co-ownership account
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct CoOwnership<T: Config<I>, I: 'static = ()> {
+        // Each co-owner and their shares
+        pub owners: BoundedVec<(T::AccountId, u32), T::MaxCoOwners>,
+        // Disposals need the approval of co-owners holding more than this part of the shares, or all of them
+        pub threshold: Perbill,
+    }
+
+    /// A sale whose price is kept in escrow until its dispute window closes, with the collectible locked with the buyer
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Escrow<T: Config<I>, I: 'static = ()> {
+        pub seller: T::AccountId,
+        pub buyer: T::AccountId,
+        pub price: BalanceOf<T, I>,
+        // The co-owners to pay if the collectible was co-owned
+        pub co_ownership: Option<CoOwnership<T, I>>,
+        // First block in which the price can be released, unless disputed
+        pub release_at: BlockNumberFor<T>,
+        pub disputed: bool,
+    }
+
+    /// The right to buy a collectible at `strike` until `expiry`, written by its owner and sold for `premium`
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct CallOption<T: Config<I>, I: 'static = ()> {
+        pub writer: T::AccountId,
+        pub premium: BalanceOf<T, I>,
+        pub strike: BalanceOf<T, I>,
+        // Last block in which the option can be exercised
+        pub expiry: BlockNumberFor<T>,
+        // `None` until the option is bought
+        pub holder: Option<T::AccountId>,
+    }
+
+    /// A purchase or sale of a collectible, as kept in the trade history of the buyer and the seller
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Trade<T: Config<I>, I: 'static = ()> {
+        pub collectible: [u8; 16],
+        // Whether the account bought the collectible, or else sold it
+        pub bought: bool,
+        pub counterparty: T::AccountId,
+        // `None` if paid in `Currency`
+        pub asset: Option<AssetIdOf<T, I>>,
+        pub price: BalanceOf<T, I>,
+        pub at: BlockNumberFor<T>,
+    }
+
+    /// An immutable record of who held every collectible at a block, as the root of a Merkle tree whose leaves are the
+    /// hashes of each `(collectible id, owner)`. Pairs of nodes are hashed in ascending order, and the last node of
+    /// odd levels is carried to the next level as is
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct HolderSnapshot<T: frame_system::Config> {
+        pub at: BlockNumberFor<T>,
+        pub root: T::Hash,
+        // The amount of leaves
+        pub collectibles: u32,
+    }
+
+    /// A transfer the owner of a collectible signed off-chain, for a sponsor to submit and pay the fees of
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct PreSignedTransfer<AccountId, BlockNumber> {
+        pub collectible: [u8; 16],
+        pub to: AccountId,
+        // Must match the owner's `TransferNonces`, so the transfer can't be replayed
+        pub nonce: u32,
+        // Last block in which the transfer can be submitted
+        pub deadline: BlockNumber,
+    }
+
+    /// Progress of a multi-block storage migration, see `migrations::v1::MigrateToV1Stepped`
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum MigrationStage {
+        /// Migrating `CollectibleMap`, resuming after the given raw storage key
+        Collectibles(Option<BoundedVec<u8, ConstU32<256>>>),
+        /// Migrating `OwnerOfCollectibles`, resuming after the given raw storage key
+        Owners(Option<BoundedVec<u8, ConstU32<256>>>),
+    }
+
+    /// Point-in-time view of the pallet, so indexers can bootstrap without replaying events
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct IndexerSnapshot<Balance> {
+        pub total_supply: u64,
+        pub per_color: Vec<(Color, u64)>,
+        pub listings: Vec<([u8; 16], Balance)>,
+        // The `blake2_256` hash of the metadata of each collectible that has some
+        pub metadata_hashes: Vec<([u8; 16], [u8; 32])>,
+        // The hash of the off-chain content of each collectible that recorded one at mint
+        pub content_hashes: Vec<([u8; 16], [u8; 32])>,
+    }
+
+    /// Maps the Collectible struct to the unique_id. Its counter is the total supply.
+    #[pallet::storage]
+    pub(super) type CollectibleMap<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, [u8; 16], Collectible<T, I>>;
+
+    /// Bumped on every generated id, so ids generated in the same extrinsic or hook differ.
+    #[pallet::storage]
+    pub(super) type IdNonce<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+    /// Track the collectibles owned by each account.
+    #[pallet::storage]
+    pub(super) type OwnerOfCollectibles<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        [u8; 16],
+        (),
+    >;
+
+    /// The amount of collectibles owned by each account. Accounts without collectibles have no entry.
+    #[pallet::storage]
+    pub(super) type OwnedCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultMaximumOwned<T: Config<I>, I: 'static>() -> Option<u32> {
+        T::MaximumOwned::get()
+    }
+
+    /// The amount of collectibles an account can own, defaults to the `MaximumOwned` constant.
+    #[pallet::storage]
+    pub(super) type MaximumOwned<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Option<u32>, ValueQuery, DefaultMaximumOwned<T, I>>;
+
+    /// The part of every sale price that is burned, set through `set_parameter`.
+    #[pallet::storage]
+    pub(super) type SaleBurn<T: Config<I>, I: 'static = ()> = StorageValue<_, Perbill, ValueQuery>;
+
+    /// Whether minters must have a judged identity, set through `set_parameter`.
+    #[pallet::storage]
+    pub(super) type MintersNeedIdentity<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    /// Whether buyers must have a judged identity, set through `set_parameter`.
+    #[pallet::storage]
+    pub(super) type BuyersNeedIdentity<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultItemDeposit<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
+        T::ItemDeposit::get()
+    }
+
+    /// The deposit held for each collectible, defaults to the `ItemDeposit` constant.
+    #[pallet::storage]
+    pub(super) type ItemDeposit<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T, I>, ValueQuery, DefaultItemDeposit<T, I>>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultNameDeposit<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
+        T::NameDeposit::get()
+    }
+
+    /// The deposit held for each named collectible, defaults to the `NameDeposit` constant.
+    #[pallet::storage]
+    pub(super) type NameDeposit<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T, I>, ValueQuery, DefaultNameDeposit<T, I>>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultNoteDeposit<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
+        T::NoteDeposit::get()
+    }
+
+    /// The deposit held for each note, defaults to the `NoteDeposit` constant.
+    #[pallet::storage]
+    pub(super) type NoteDeposit<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T, I>, ValueQuery, DefaultNoteDeposit<T, I>>;
+
+    /// The hash of the off-chain content of each collectible, e.g. its image, set when it's minted and never changed
+    /// so buyers can check the content its metadata points to wasn't swapped.
+    #[pallet::storage]
+    pub(super) type ContentHashes<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], [u8; 32]>;
+
+    /// The royalty of each creator's collectibles without one of their own, set by the creator.
+    #[pallet::storage]
+    pub(super) type DefaultRoyalties<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Royalty<T::AccountId>>;
+
+    /// The lowest and highest rates creators can set their collectibles' royalties at, set by governance. `0` to
+    /// `MaxRoyalty` if unset.
+    #[pallet::storage]
+    pub(super) type RoyaltyRange<T: Config<I>, I: 'static = ()> = StorageValue<_, (Permill, Permill)>;
+
+    /// Total amount of collectibles destroyed.
+    #[pallet::storage]
+    pub type BurnedCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+    /// Total native currency burned from sales.
+    #[pallet::storage]
+    pub type BurnedFromSales<T: Config<I>, I: 'static = ()> = StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+    /// Total of each asset burned from sales paid in it.
+    #[pallet::storage]
+    pub type AssetsBurnedFromSales<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T, I>, BalanceOf<T, I>, ValueQuery>;
+
+    /// The amount of accounts owning at least one collectible.
+    #[pallet::storage]
+    pub(super) type HoldersCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// The amount of collectibles of each color.
+    #[pallet::storage]
+    pub(super) type ColorSupply<T: Config<I>, I: 'static = ()> = StorageMap<_, Twox64Concat, Color, u32, ValueQuery>;
+
+    /// The collectibles of each color.
+    #[pallet::storage]
+    pub(super) type CollectiblesByColor<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Twox64Concat, Color, Blake2_128Concat, [u8; 16], ()>;
+
+    /// Collectibles reported stolen. They can't be listed, bought or transferred, only force transferred.
+    #[pallet::storage]
+    pub(super) type StolenCollectibles<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], ()>;
+
+    /// Collectibles flagged by a moderator, with the hash of the reason. They're hidden from the listings.
+    #[pallet::storage]
+    pub(super) type FlaggedCollectibles<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], T::Hash>;
+
+    /// Creators vetted by governance, so marketplaces can tell official drops from copycats.
+    #[pallet::storage]
+    pub(super) type VerifiedCreators<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+    /// While set, every user-facing call but `remove_from_market` is rejected.
+    #[pallet::storage]
+    pub(super) type Paused<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultTransfersEnabled() -> bool {
+        true
+    }
+
+    /// Governance switch for every transfer path but `force_transfer`, to cut off a compromised bridge or marketplace.
+    #[pallet::storage]
+    pub(super) type TransfersEnabled<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, bool, ValueQuery, DefaultTransfersEnabled>;
+
+    /// Collectibles locked by a feature of the pallet or by another pallet, with what locked them. They can't be
+    /// transferred, sold or destroyed until unlocked.
+    #[pallet::storage]
+    pub(super) type LockedCollectibles<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], LockReason>;
+
+    /// The last block in which each collectible changed hands or its listing changed. Buying and destroying a collectible
+    /// touched in the current block is rejected, so their outcome doesn't depend on the order of the block.
+    #[pallet::storage]
+    pub(super) type LastTouched<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BlockNumberFor<T>>;
+
+    /// The deposit currently held for each collectible from its owner. Collectibles created before deposits were
+    /// introduced have none.
+    #[pallet::storage]
+    pub(super) type ItemDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BalanceOf<T, I>>;
+
+    /// The asset each collectible on sale is priced in, for those not priced in `Currency`.
+    #[pallet::storage]
+    pub(super) type ListingAssets<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], AssetIdOf<T, I>>;
+
+    /// Alternative prices in other assets for collectibles on sale. Buyers can pay any of them instead of the listed price.
+    #[pallet::storage]
+    pub(super) type AssetQuotes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], AssetQuotesOf<T, I>, ValueQuery>;
+
+    /// Collectibles on sale whose price is set in the reference unit of `PriceOracle` and converted when bought.
+    #[pallet::storage]
+    pub(super) type ReferencePriced<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], ()>;
+
+    /// Fractionalized collectibles, with the asset of their shares and the amount of shares minted. They stay locked
+    /// under their owner until all the shares are burned with `unify`, which destroys the asset.
+    #[pallet::storage]
+    pub(super) type Fractionalized<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (AssetIdOf<T, I>, BalanceOf<T, I>)>;
+
+    /// Ongoing buyouts of fractionalized collectibles.
+    #[pallet::storage]
+    pub(super) type Buyouts<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], Buyout<T, I>>;
+
+    /// Ongoing buyouts by the first block they can be settled in, so the offchain worker only reads the due ones
+    #[pallet::storage]
+    pub(super) type EndingBuyouts<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, [u8; 16], ()>;
+
+    /// Proceeds of settled buyouts not redeemed yet: the share asset, the shares left and the funds left for them.
+    #[pallet::storage]
+    pub(super) type BuyoutProceeds<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (AssetIdOf<T, I>, BalanceOf<T, I>, BalanceOf<T, I>)>;
+
+    /// The funds backing each reserve-backed collectible, kept in its `backing_account` until the collectible is destroyed.
+    #[pallet::storage]
+    pub(super) type Backing<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], BalanceOf<T, I>>;
+
+    /// Loans requested or taken against collectibles. The collateral stays locked until the loan is repaid, cancelled or
+    /// defaulted.
+    #[pallet::storage]
+    pub(super) type Loans<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], Loan<T, I>>;
+
+    /// Collectibles offered for rent, with their price per `RentalPeriod` and the maximum amount of periods.
+    #[pallet::storage]
+    pub(super) type RentalOffers<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BalanceOf<T, I>, u32)>;
+
+    /// Ongoing rentals, with the renter and the last block of the term. The collectible is locked until it's over.
+    #[pallet::storage]
+    pub(super) type Rentals<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, BlockNumberFor<T>)>;
+
+    /// Rentals paid period by period. They're renewed when their term is over, as long as the renter's hold covers
+    /// another period
+    #[pallet::storage]
+    pub(super) type RentStreams<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], RentStream<T, I>>;
+
+    /// Rentals queued to expire at each block, closed automatically in idle time. There's no limit per block, whatever
+    /// doesn't fit in a block's idle time is closed in the following ones
+    #[pallet::storage]
+    pub(super) type RentalExpiries<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, [u8; 16], ()>;
+
+    /// The earliest block whose rental expiries haven't been processed yet. Unset if none is queued
+    #[pallet::storage]
+    pub(super) type NextRentalExpiry<T: Config<I>, I: 'static = ()> = StorageValue<_, BlockNumberFor<T>>;
+
+    /// Amount of rental expiries queued
+    #[pallet::storage]
+    pub(super) type QueuedRentalExpiries<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// Transfers scheduled by the owners of collectibles, with their recipient and the block they execute at. The
+    /// collectibles stay locked until then
+    #[pallet::storage]
+    pub(super) type ScheduledTransfers<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, BlockNumberFor<T>)>;
+
+    /// Collectibles whose scheduled transfer executes at each block
+    #[pallet::storage]
+    pub(super) type ScheduledTransferQueue<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<[u8; 16], T::MaxScheduledTransfers>, ValueQuery>;
+
+    /// Rent-to-own terms of collectibles offered for rent: the purchase price and the share of each rent payment that
+    /// counts towards it.
+    #[pallet::storage]
+    pub(super) type RentToOwnTerms<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BalanceOf<T, I>, Perbill)>;
+
+    /// The renter accruing credit towards the purchase of a rent-to-own collectible, and the credit accrued so far.
+    #[pallet::storage]
+    pub(super) type RentCredit<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, BalanceOf<T, I>)>;
+
+    /// Staked collectibles, with the staker and the block rewards are accrued from. They're locked until unstaked
+    #[pallet::storage]
+    pub(super) type Stakes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, BlockNumberFor<T>)>;
+
+    /// Revenue distributed to each collectible since the distributions started, accumulated over every deposit
+    #[pallet::storage]
+    pub(super) type RevenuePerItem<T: Config<I>, I: 'static = ()> = StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+    /// The value of `RevenuePerItem` up to which each collectible's revenue has been credited to its holders.
+    /// Collectibles minted after a deposit don't share in it
+    #[pallet::storage]
+    pub(super) type RevenueCheckpoints<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BalanceOf<T, I>, ValueQuery>;
+
+    /// Revenue credited to each account and not claimed yet
+    #[pallet::storage]
+    pub(super) type RevenueOwed<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, ValueQuery>;
+
+    /// The beneficiary donated a cut of every sale, and the cut
+    #[pallet::storage]
+    pub(super) type CharityCut<T: Config<I>, I: 'static = ()> = StorageValue<_, (T::AccountId, Perbill)>;
+
+    /// The tax charged to the sender of a plain transfer, and who receives it
+    #[pallet::storage]
+    pub(super) type TransferTax<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, (TransferFee<BalanceOf<T, I>>, FeeRecipient)>;
+
+    /// The last price each collectible was sold for in the native currency
+    #[pallet::storage]
+    pub(super) type LastSalePrice<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BalanceOf<T, I>>;
+
+    /// The royalty of each collectible, set by its creator
+    #[pallet::storage]
+    pub(super) type Royalties<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], Royalty<T::AccountId>>;
+
+    /// How royalties are enforced on plain transfers, not at all if unset
+    #[pallet::storage]
+    pub(super) type RoyaltyEnforcementMode<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, RoyaltyEnforcement<BalanceOf<T, I>>>;
+
+    /// Accounts that collectibles can be transferred to freely while royalties are enforced, e.g. custodians
+    #[pallet::storage]
+    pub(super) type RoyaltyExempt<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Collectibles burned through the `nonfungibles::Mutate` interface, e.g. when XCM withdraws them from a sibling's
+    /// sovereign account. They're kept locked instead, and minting them again through the interface releases them
+    #[pallet::storage]
+    pub(super) type Parked<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], ()>;
+
+    /// Collectibles exported to Asset Hub, locked here until imported back
+    #[pallet::storage]
+    pub(super) type Exported<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], ()>;
+
+    /// The account each collectible's owner approved to transfer it on their behalf. Cleared when it changes hands.
+    #[pallet::storage]
+    pub(super) type Approvals<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], T::AccountId>;
+
+    /// The nonce each account's next pre-signed transfer must carry
+    #[pallet::storage]
+    pub(super) type TransferNonces<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The account each collectible's owner allowed to list and delist it, e.g. a hot key managing a cold wallet's
+    /// collectibles. Cleared when it changes hands.
+    #[pallet::storage]
+    pub(super) type Managers<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], T::AccountId>;
+
+    /// Co-owned collectibles and their co-owners.
+    #[pallet::storage]
+    pub(super) type CoOwned<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], CoOwnership<T, I>>;
+
+    /// The transfer proposed for each co-owned collectible and the co-owners that approved it so far.
+    #[pallet::storage]
+    pub(super) type DisposalApprovals<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 16],
+        (T::AccountId, BoundedVec<T::AccountId, T::MaxCoOwners>),
+    >;
+
+    /// The price proposed for each co-owned collectible, the co-owners that approved it so far and the last block
+    /// they can be joined in. Once expired, the next approval starts over.
+    #[pallet::storage]
+    pub(super) type ListingApprovals<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 16],
+        (BalanceOf<T, I>, BoundedVec<T::AccountId, T::MaxCoOwners>, BlockNumberFor<T>),
+    >;
+
+    /// The co-owners that approved delisting each co-owned collectible so far and the last block they can be joined in.
+    /// Once expired, the next approval starts over.
+    #[pallet::storage]
+    pub(super) type DelistingApprovals<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BoundedVec<T::AccountId, T::MaxCoOwners>, BlockNumberFor<T>)>;
+
+    /// The last block each listing can be bought in, if its owner set one. Expired listings are delisted by the
+    /// offchain worker.
+    #[pallet::storage]
+    pub(super) type ListingExpiries<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BlockNumberFor<T>>;
+
+    /// Listings with an expiry by the first block they're expired in, so the offchain worker only reads the due ones
+    #[pallet::storage]
+    pub(super) type ExpiringListings<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, [u8; 16], ()>;
+
+    /// The condition buyers of each listing must meet, if its owner set one.
+    #[pallet::storage]
+    pub(super) type ListingConditions<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 16],
+        <T::BuyerQualification as BuyerQualification<T::AccountId>>::Condition
+    >;
+
+    /// The price each listing switches to from the given block on, applied when the price is read.
+    #[pallet::storage]
+    pub(super) type ScheduledPrices<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BalanceOf<T, I>, BlockNumberFor<T>)>;
+
+    /// Listings whose price drops every block from the block they were listed in: the drop per block and the floor it
+    /// stops at.
+    #[pallet::storage]
+    pub(super) type DecliningPrices<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BalanceOf<T, I>, BalanceOf<T, I>, BlockNumberFor<T>)>;
+
+    /// How long collectibles of each creator that opted into it can't be listed after changing hands, against wash
+    /// trading and bots.
+    #[pallet::storage]
+    pub(super) type RelistCooldowns<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The block each collectible under a relist cooldown last changed hands in.
+    #[pallet::storage]
+    pub(super) type Acquisitions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BlockNumberFor<T>>;
+
+    /// The last block each account claimed a collectible from the faucet in.
+    #[pallet::storage]
+    pub(super) type LastFaucetClaim<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The last block with faucet claims and how many there were in it.
+    #[pallet::storage]
+    pub(super) type FaucetClaims<T: Config<I>, I: 'static = ()> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+    /// The escrowed sale of each collectible, locked with its buyer until it's released or refunded.
+    #[pallet::storage]
+    pub(super) type Escrows<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], Escrow<T, I>>;
+
+    /// The call option written on each collectible, locked for the option's lifetime.
+    #[pallet::storage]
+    pub(super) type CallOptions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], CallOption<T, I>>;
+
+    /// Collectibles gifted by their owner, locked until the recipient claims them: the sender, the recipient and the
+    /// last block to claim them in.
+    #[pallet::storage]
+    pub(super) type Gifts<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, T::AccountId, BlockNumberFor<T>)>;
+
+    /// Gifts reverting to their senders at each block, if still unclaimed
+    #[pallet::storage]
+    pub(super) type GiftExpiryQueue<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<[u8; 16], T::MaxGiftExpiries>, ValueQuery>;
+
+    /// Collectibles airdropped by their owner and waiting for the recipient to claim them, locked until then.
+    #[pallet::storage]
+    pub(super) type Airdrops<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (T::AccountId, T::AccountId)>;
+
+    /// The name its owner gave each collectible, and the deposit held for it.
+    #[pallet::storage]
+    pub(super) type Names<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], (BoundedVec<u8, T::NameLimit>, BalanceOf<T, I>)>;
+
+    /// The collectible holding each name, if `UniqueNames`.
+    #[pallet::storage]
+    pub(super) type NamedCollectibles<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::NameLimit>, [u8; 16]>;
+
+    /// The note each owner keeps on their collectibles, e.g. a custodian's internal reference, and the deposit held for
+    /// it. Cleared when the collectible changes hands.
+    #[pallet::storage]
+    pub(super) type Notes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        [u8; 16],
+        (BoundedVec<u8, T::NoteLimit>, BalanceOf<T, I>)
+    >;
+
+    /// The only accounts each restricted collectible, e.g. a prototype or a credential, can be transferred to, managed
+    /// by its creator.
+    #[pallet::storage]
+    pub(super) type AllowedHolders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BoundedVec<T::AccountId, T::MaxAllowedHolders>>;
+
+    /// The latest `MaxTradeHistory` trades of each account, oldest first.
+    #[pallet::storage]
+    pub(super) type TradeHistory<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<Trade<T, I>, T::MaxTradeHistory>, ValueQuery>;
+
+    /// Holder snapshots, by the id `snapshot_holders` gave them.
+    #[pallet::storage]
+    pub(super) type HolderSnapshots<T: Config<I>, I: 'static = ()> = StorageMap<_, Twox64Concat, u32, HolderSnapshot<T>>;
+
+    /// The id of the next holder snapshot.
+    #[pallet::storage]
+    pub(super) type NextSnapshotId<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// How many more collectibles each pallet can mint through `RewardCollectible`.
+    #[pallet::storage]
+    pub(super) type RewardQuotas<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, PalletId, u32, ValueQuery>;
+
+    /// Collectibles whose owner paused trading them: they can't be bought until the owner resumes it. Cleared when
+    /// they change hands.
+    #[pallet::storage]
+    pub(super) type TradingPaused<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, [u8; 16], ()>;
+
+    /// The metadata of each collectible, e.g. imported from `pallet_nfts`.
+    #[pallet::storage]
+    pub(super) type Metadata<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, [u8; 16], BoundedVec<u8, T::StringLimit>>;
+
+    /// The attributes of each collectible, e.g. imported from `pallet_nfts`.
+    #[pallet::storage]
+    pub(super) type Attributes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 16],
+        Blake2_128Concat,
+        BoundedVec<u8, T::KeyLimit>,
+        BoundedVec<u8, T::ValueLimit>,
+    >;
+
+    /// Set while a multi-block migration is ongoing. User-facing calls are rejected until it's over.
+    #[pallet::storage]
+    pub(super) type OngoingMigration<T: Config<I>, I: 'static = ()> = StorageValue<_, MigrationStage>;
+
+    #[pallet::error]
+    pub enum Error<T, I = ()> {
+        /// Each collectible must have a unique identifier
+        DuplicateCollectible,
+        /// An account can't exceed the `MaximumOwned` parameter
+        MaximumCollectiblesOwned,
+        /// The total supply of collectibles can't exceed the u32 limit
+        BoundsOverflow,
+        /// The collectible doesn't exist
+        NoCollectible,
+        /// You are not the owner
+        NotOwner,
+        /// Trying to transfer a collectible to yourself
+        TransferToSelf,
+        /// Error sent when trying to buy or get/remove the price of a collectible which's not on sale
+        CollectibleNotForSale,
+        /// Error sent if trying to buy a collectible under its price
+        OfferedPriceTooLow,
+        /// The collectible is locked and can't be transferred, sold or destroyed
+        CollectibleLocked,
+        /// The collectible is already locked
+        AlreadyLocked,
+        /// The collectible isn't locked
+        NotLocked,
+        /// The pallet is paused
+        PalletPaused,
+        /// The storage is being migrated
+        MigrationOngoing,
+        /// The collectible has been reported stolen
+        CollectibleStolen,
+        /// The collectible hasn't been reported stolen
+        CollectibleNotStolen,
+        /// The collectible hasn't been flagged
+        NotFlagged,
+        /// The account is already a verified creator
+        AlreadyVerified,
+        /// The account isn't a verified creator
+        NotVerified,
+        /// Transfers are currently disabled
+        TransfersDisabled,
+        /// The collectible isn't indexed under its owner. This means the storage is corrupted
+        CorruptedOwnershipIndex,
+        /// The collectible is on sale, it must be retired from the market first
+        CollectibleOnSale,
+        /// The collectible already changed hands or its listing changed in this block
+        TouchedThisBlock,
+        /// The collectible is priced in another asset
+        WrongPaymentAsset,
+        /// The price oracle has no rate to convert the reference price
+        OracleUnavailable,
+        /// The same asset is quoted more than once
+        DuplicateQuote,
+        /// The collectible isn't fractionalized
+        NotFractionalized,
+        /// A collectible can't be split into zero shares
+        NoShares,
+        /// The collectible is being bought out
+        BuyoutOngoing,
+        /// The collectible isn't being bought out
+        NoBuyout,
+        /// The buyout can still be outbid
+        BuyoutNotOver,
+        /// The buyout can't be outbid anymore
+        BuyoutOver,
+        /// The bid doesn't beat the leading one
+        BidTooLow,
+        /// Only shareholders can start a buyout
+        NotShareholder,
+        /// There are no buyout proceeds to redeem
+        NothingToRedeem,
+        /// The backing must be at least the existential deposit, so its pot can exist
+        BackingTooLow,
+        /// There's no loan against the collectible
+        NoLoan,
+        /// The loan has already been funded
+        LoanFunded,
+        /// The loan hasn't been funded yet
+        LoanNotFunded,
+        /// The loan's deadline has passed
+        LoanExpired,
+        /// The loan can still be repaid
+        LoanNotExpired,
+        /// Only the borrower can do this
+        NotBorrower,
+        /// Borrowers can't fund their own loans
+        BorrowerIsLender,
+        /// The collectible isn't offered for rent
+        NotForRent,
+        /// The amount of periods is zero or over the maximum of the offer
+        InvalidRentalPeriods,
+        /// The collectible isn't rented
+        NotRented,
+        /// The rental terms of a rented collectible can't change until the rental is over
+        RentalOngoing,
+        /// The rental term isn't over yet
+        RentalNotOver,
+        /// The collectible can't be bought by its renter
+        NotRentToOwn,
+        /// The funds held don't cover a rental period
+        RentHoldTooLow,
+        /// The rental isn't paid period by period
+        NotStreaming,
+        /// The collectible isn't staked
+        NotStaked,
+        /// The caller didn't stake the collectible
+        NotStaker,
+        /// There are no collectibles to distribute revenue to
+        NoHolders,
+        /// The deposit is too small to give every collectible a share
+        DepositTooLow,
+        /// The caller didn't create the collectible
+        NotCreator,
+        /// Royalties are enforced, the collectible can only be transferred freely to exempt accounts
+        RoyaltyEnforced,
+        /// The royalty rate is above `MaxRoyalty`
+        RoyaltyTooHigh,
+        /// The collectible isn't parked, so it can't be minted through the `nonfungibles` interface
+        NotParked,
+        /// The collectible hasn't been exported
+        NotExported,
+        /// The caller is neither the owner of the collectible nor approved to transfer it
+        NotApproved,
+        /// The imported metadata is longer than `StringLimit`
+        MetadataTooLong,
+        /// The imported attribute value is longer than `ValueLimit`
+        AttributeTooLong,
+        /// The account must have an identity judged by a registrar
+        NoJudgedIdentity,
+        /// Scheduled transfers must execute in a future block
+        ScheduleInPast,
+        /// The block already has `MaxScheduledTransfers` scheduled transfers
+        TooManyScheduledTransfers,
+        /// The collectible has no scheduled transfer
+        NotScheduled,
+        /// You are neither the owner nor the manager
+        NotOwnerOrManager,
+        /// Co-owners must be distinct and hold at least one share each
+        InvalidCoOwners,
+        /// The collectible is already co-owned
+        AlreadyCoOwned,
+        /// The collectible isn't co-owned
+        NotCoOwned,
+        /// You aren't a co-owner of the collectible
+        NotCoOwner,
+        /// You already approved this disposal
+        AlreadyApproved,
+        /// The listing has expired
+        ListingExpired,
+        /// The listing hasn't expired
+        ListingNotExpired,
+        /// The faucet is disabled
+        FaucetDisabled,
+        /// The account claimed from the faucet too recently
+        FaucetCooldown,
+        /// The faucet handed out `MaxFaucetClaimsPerBlock` collectibles in this block already
+        FaucetExhausted,
+        /// The collectible hasn't been airdropped
+        NoAirdrop,
+        /// The collectible was airdropped to another account
+        NotRecipient,
+        /// The signature doesn't match the pre-signed transfer and its signer
+        WrongSignature,
+        /// The pre-signed transfer's nonce isn't the signer's next one
+        WrongNonce,
+        /// The pre-signed transfer's deadline has passed
+        DeadlinePassed,
+        /// The purchase order's `valid_until` block has passed
+        OrderExpired,
+        /// A declining price's floor is above its starting price
+        FloorAbovePrice,
+        /// The collectible changed hands too recently to be listed, as its creator set
+        RelistCooldown,
+        /// The listing expiry is further than `MaxListingDuration` away
+        ListingTooLong,
+        /// No call option was written on the collectible
+        NoOption,
+        /// The call option was already bought
+        OptionTaken,
+        /// The call option can't be exercised or bought anymore
+        OptionExpired,
+        /// The call option was bought and can still be exercised
+        OptionActive,
+        /// Only the holder of a call option can exercise it
+        NotOptionHolder,
+        /// The collectible isn't in escrow
+        NoEscrow,
+        /// The escrowed sale can't be released yet
+        EscrowNotOver,
+        /// The escrowed sale can't be disputed anymore
+        EscrowOver,
+        /// The escrowed sale is disputed and waits for the arbitrator
+        EscrowDisputed,
+        /// The escrowed sale isn't disputed
+        EscrowNotDisputed,
+        /// Only the buyer of an escrowed sale can dispute it
+        NotBuyer,
+        /// The collectible hasn't been gifted
+        NoGift,
+        /// `MaxGiftExpiries` gifts already revert in the block after the claim deadline
+        TooManyGiftExpiries,
+        /// Another collectible already has this name
+        NameTaken,
+        /// The collectible has no name
+        NoName,
+        /// The owner keeps no note on the collectible
+        NoNote,
+        /// The owner paused trading the collectible
+        TradingPaused,
+        /// The buyer doesn't meet the condition of the listing
+        BuyerNotQualified,
+        /// The collectible is restricted to holders its creator allowed
+        HolderNotAllowed,
+        /// The collectible already has `MaxAllowedHolders` allowed holders
+        TooManyAllowedHolders,
+        /// The pallet minting a reward used up its quota
+        RewardQuotaExhausted,
+        /// There are more than `MaxSnapshotHolders` collectibles to snapshot
+        TooManyToSnapshot,
+        /// Only the writer of a call option can close it
+        NotOptionWriter,
+        /// Only the sender of an airdrop can cancel it
+        NotSender,
+        /// The `pallet_nfts` item doesn't exist
+        NoNftsItem,
+        /// Unifying a collectible takes all of its shares
+        InsufficientShares,
+        /// The royalty rate is outside the range governance allows
+        RoyaltyOutOfRange,
+        /// The lowest rate of the royalty range is above its highest, or its highest above `MaxRoyalty`
+        InvalidRoyaltyRange
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config<I>, I: 'static = ()> {
+        /// A new collectible was successfully created
+        CollectibleCreated { collectible: [u8; 16], owner: T::AccountId, color: Color, verified: bool },
+        /// A collectible backed by funds locked in its own pot has been created
+        BackedCollectibleCreated { collectible: [u8; 16], owner: T::AccountId, backing: BalanceOf<T, I> },
+        /// An owner has offered a collectible as collateral for a loan
+        LoanRequested { collectible: [u8; 16], borrower: T::AccountId, amount: BalanceOf<T, I>, repayment: BalanceOf<T, I>, duration: BlockNumberFor<T> },
+        /// A lender has funded a loan, which must be repaid by `deadline`
+        LoanFunded { collectible: [u8; 16], lender: T::AccountId, deadline: BlockNumberFor<T> },
+        /// A loan has been repaid and its collateral released
+        LoanRepaid { collectible: [u8; 16] },
+        /// A loan request has been withdrawn before being funded
+        LoanCancelled { collectible: [u8; 16] },
+        /// A loan wasn't repaid in time and its collateral went to the lender
+        LoanDefaulted { collectible: [u8; 16], lender: T::AccountId },
+        /// A collectible's owner has offered it for rent
+        ListedForRent { collectible: [u8; 16], price_per_period: BalanceOf<T, I>, max_periods: u32 },
+        /// A collectible is no longer offered for rent
+        RentListingRemoved { collectible: [u8; 16] },
+        /// A collectible offered for rent can be bought by its renters, who accrue `share` of their rent towards `price`
+        RentToOwnOffered { collectible: [u8; 16], price: BalanceOf<T, I>, share: Perbill },
+        /// A renter has bought the collectible it was renting, paying what its credit didn't cover
+        RentToOwnExercised { collectible: [u8; 16], renter: T::AccountId, paid: BalanceOf<T, I> },
+        /// A collectible has been rented until block `until`
+        Rented { collectible: [u8; 16], renter: T::AccountId, periods: u32, until: BlockNumberFor<T> },
+        /// A rental term is over and the collectible is back to its owner
+        RentalEnded { collectible: [u8; 16], renter: T::AccountId },
+        /// A streaming rental has been paid for another period
+        RentalRenewed { collectible: [u8; 16], renter: T::AccountId, until: BlockNumberFor<T> },
+        /// The funds held to pay a streaming rental have changed
+        RentStreamHeld { collectible: [u8; 16], renter: T::AccountId, held: BalanceOf<T, I> },
+        /// A streaming rental won't be renewed anymore, its hold has been released
+        RentStreamStopped { collectible: [u8; 16], renter: T::AccountId },
+        /// A collectible has been staked
+        Staked { collectible: [u8; 16], staker: T::AccountId },
+        /// A collectible has been unstaked
+        Unstaked { collectible: [u8; 16], staker: T::AccountId },
+        /// Staking rewards have been paid
+        StakingRewardsPaid { collectible: [u8; 16], staker: T::AccountId, amount: BalanceOf<T, I> },
+        /// The charity receiving a cut of every sale has been set, or removed if `None`
+        CharityCutSet { charity_cut: Option<(T::AccountId, Perbill)> },
+        /// The tax charged on plain transfers has been set, or removed if `None`
+        TransferTaxSet { tax: Option<(TransferFee<BalanceOf<T, I>>, FeeRecipient)> },
+        /// The sender of a plain transfer has paid the transfer tax
+        TransferTaxPaid { collectible: [u8; 16], payer: T::AccountId, recipient: T::AccountId, amount: BalanceOf<T, I> },
+        /// The creator of a collectible has set its royalty, or removed it if `None`. `old` is the royalty it replaced
+        RoyaltySet { collectible: [u8; 16], old: Option<Royalty<T::AccountId>>, royalty: Option<Royalty<T::AccountId>> },
+        /// A creator has set the royalty of their collectibles without one of their own, or removed it if `None`
+        DefaultRoyaltySet { creator: T::AccountId, royalty: Option<Royalty<T::AccountId>> },
+        /// Governance has set the range of the royalty rates creators can set, or removed it if `None`
+        RoyaltyRangeSet { range: Option<(Permill, Permill)> },
+        /// Governance has overridden the royalty of a collectible, removing it if `None`. `old` is the royalty it replaced
+        RoyaltyForceSet { collectible: [u8; 16], old: Option<Royalty<T::AccountId>>, royalty: Option<Royalty<T::AccountId>> },
+        /// The way royalties are enforced on plain transfers has changed, `None` meaning they aren't
+        RoyaltyEnforcementSet { mode: Option<RoyaltyEnforcement<BalanceOf<T, I>>> },
+        /// An account has been exempted from royalty enforcement, or the exemption lifted
+        RoyaltyExemptionSet { account: T::AccountId, exempt: bool },
+        /// The minimum royalty has been paid on a plain transfer
+        TransferRoyaltyPaid { collectible: [u8; 16], payer: T::AccountId, beneficiary: T::AccountId, amount: BalanceOf<T, I> },
+        /// Revenue has been deposited for the holders, each collectible getting `per_item`
+        RevenueDeposited { depositor: T::AccountId, amount: BalanceOf<T, I>, per_item: BalanceOf<T, I> },
+        /// A holder has claimed its share of the distributed revenue
+        RevenueClaimed { holder: T::AccountId, amount: BalanceOf<T, I> },
+        /// A collectible was successfully transferred.
+        TransferSucceeded { from: T::AccountId, to: T::AccountId, collectible: [u8; 16], color: Color },
+        /// A collectible's owner has set a price for it
+        PriceSet { collectible: [u8;16], price: BalanceOf<T, I> },
+        /// A collectible's owner has retired it from the market
+        NotLongerOnSale { collectible: [u8;16] },
+        /// A purchase occured. `price` is the total paid by the buyer, `charity`, `burned`, `royalty` and `proceeds` the
+        /// parts of it donated, burned, paid to the royalty beneficiary and paid to the seller, or its co-owners
+        Sold {
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            collectible: [u8;16],
+            color: Color,
+            price: BalanceOf<T, I>,
+            charity: BalanceOf<T, I>,
+            burned: BalanceOf<T, I>,
+            royalty: BalanceOf<T, I>,
+            proceeds: BalanceOf<T, I>
+        },
+        /// A collectible's owner has set a price for it in an asset
+        PriceSetInAsset { collectible: [u8;16], asset: AssetIdOf<T, I>, price: BalanceOf<T, I> },
+        /// A collectible's owner has set a price for it in the oracle's reference unit
+        ReferencePriceSet { collectible: [u8;16], price: BalanceOf<T, I> },
+        /// A collectible's owner has set the alternative asset quotes of its listing
+        AssetQuotesSet { collectible: [u8;16], quotes: AssetQuotesOf<T, I> },
+        /// A collectible has been split into fungible shares
+        CollectibleFractionalized { collectible: [u8;16], asset: AssetIdOf<T, I>, shares: BalanceOf<T, I> },
+        /// All the shares of a collectible have been burned and the collectible released to their holder
+        CollectibleUnified { collectible: [u8;16], owner: T::AccountId },
+        /// A new leading bid has been placed to buy out a fractionalized collectible
+        BuyoutBid { collectible: [u8;16], bidder: T::AccountId, bid: BalanceOf<T, I> },
+        /// A buyout has been settled, its proceeds can be redeemed by the shareholders
+        BuyoutSettled { collectible: [u8;16], buyer: T::AccountId, price: BalanceOf<T, I> },
+        /// A shareholder has burned its shares for its part of the buyout proceeds
+        SharesRedeemed { collectible: [u8;16], who: T::AccountId, shares: BalanceOf<T, I>, payout: BalanceOf<T, I> },
+        /// A purchase paid in an asset occured. `price` is the total paid by the buyer, `charity`, `burned` and `royalty`
+        /// the parts of it donated, burned and paid to the royalty beneficiary
+        SoldInAsset {
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            collectible: [u8;16],
+            color: Color,
+            asset: AssetIdOf<T, I>,
+            price: BalanceOf<T, I>,
+            charity: BalanceOf<T, I>,
+            burned: BalanceOf<T, I>,
+            royalty: BalanceOf<T, I>,
+            proceeds: BalanceOf<T, I>
+        },
+        /// A collectible's been destroyed. `deposit_refunded` tells whether its owner got an item deposit back
+        CollectibleDestroyed { collectible: [u8;16], owner: T::AccountId, deposit_refunded: bool },
+        /// A collectible has been locked, by a feature of the pallet or by another pallet
+        CollectibleLocked { collectible: [u8;16], reason: LockReason },
+        /// A collectible has been unlocked
+        CollectibleUnlocked { collectible: [u8;16], reason: LockReason },
+        /// A collectible has been bought from another chain, `buyer` being the local account representing the remote one
+        BoughtRemotely { collectible: [u8;16], buyer: T::AccountId },
+        /// A collectible has been exported to be re-created under `pallet_nfts` on Asset Hub, owned by `beneficiary`
+        CollectibleExported { collectible: [u8;16], color: Color, creator: T::AccountId, beneficiary: T::AccountId },
+        /// A collectible exported to Asset Hub has been imported back and released to `owner`
+        CollectibleImported { collectible: [u8;16], owner: T::AccountId },
+        /// A collectible has been withdrawn through the `nonfungibles` interface and parked until it's deposited again
+        CollectibleParked { collectible: [u8;16] },
+        /// A parked collectible has been deposited to `owner` through the `nonfungibles` interface
+        CollectibleUnparked { collectible: [u8;16], owner: T::AccountId },
+        /// A collectible was transferred by the force origin
+        ForceTransferred { from: T::AccountId, to: T::AccountId, collectible: [u8;16] },
+        /// A collectible was destroyed by the force origin
+        ForceDestroyed { owner: T::AccountId, collectible: [u8;16], deposit_refunded: bool },
+        /// The pallet has been paused
+        Paused,
+        /// The pallet has been unpaused
+        Unpaused,
+        /// A runtime parameter has been updated
+        ParameterSet { parameter: Parameter<BalanceOf<T, I>> },
+        /// A collectible has been reported stolen and retired from the market
+        ReportedStolen { collectible: [u8;16] },
+        /// A collectible is no longer considered stolen
+        StolenFlagCleared { collectible: [u8;16] },
+        /// A moderator has flagged a collectible
+        CollectibleFlagged { collectible: [u8;16], reason: T::Hash },
+        /// A moderation flag has been cleared
+        FlagCleared { collectible: [u8;16] },
+        /// An account has been added to the verified creators
+        CreatorVerified { creator: T::AccountId },
+        /// An account has been removed from the verified creators
+        CreatorUnverified { creator: T::AccountId },
+        /// Transfers have been enabled or disabled
+        TransfersToggled { enabled: bool },
+        /// The owner of a collectible has approved `delegate` to transfer it, or revoked the approval if `None`
+        ApprovalSet { collectible: [u8;16], owner: T::AccountId, delegate: Option<T::AccountId> },
+        /// An item of a `pallet_nfts` collection has been imported as a collectible
+        NftImported { collectible: [u8;16], collection: T::NftsCollectionId, item: T::NftsItemId },
+        /// The hash of a collectible's off-chain content has been recorded at mint
+        ContentHashSet { collectible: [u8;16], hash: [u8; 32] },
+        /// The metadata of a collectible has been set
+        MetadataSet { collectible: [u8;16], data: BoundedVec<u8, T::StringLimit> },
+        /// The metadata of a collectible has been removed
+        MetadataCleared { collectible: [u8;16] },
+        /// An attribute of a collectible has been set
+        AttributeSet { collectible: [u8;16], key: BoundedVec<u8, T::KeyLimit>, value: BoundedVec<u8, T::ValueLimit> },
+        /// An attribute of a collectible has been removed
+        AttributeCleared { collectible: [u8;16], key: BoundedVec<u8, T::KeyLimit> },
+        /// The owner of a collectible has scheduled its transfer to `to` at block `at`
+        TransferScheduled { collectible: [u8;16], to: T::AccountId, at: BlockNumberFor<T> },
+        /// A scheduled transfer has been cancelled by the owner
+        ScheduledTransferCancelled { collectible: [u8;16] },
+        /// A scheduled transfer couldn't execute, the collectible stays with its owner, unlocked
+        ScheduledTransferFailed { collectible: [u8;16], error: DispatchError },
+        /// The owner of a collectible has set its manager, or removed it if `None`
+        ManagerSet { collectible: [u8;16], manager: Option<T::AccountId> },
+        /// The owner of a collectible has shared it with co-owners, moving it to its co-ownership account
+        OwnershipShared { collectible: [u8;16], owners: BoundedVec<(T::AccountId, u32), T::MaxCoOwners>, threshold: Perbill },
+        /// A co-owner has approved transferring a co-owned collectible to `to`
+        DisposalApproved { collectible: [u8;16], co_owner: T::AccountId, to: T::AccountId },
+        /// A co-owner has approved listing a co-owned collectible at `price`
+        ListingApproved { collectible: [u8;16], co_owner: T::AccountId, price: BalanceOf<T, I> },
+        /// A co-owner has approved delisting a co-owned collectible
+        DelistingApproved { collectible: [u8;16], co_owner: T::AccountId },
+        /// The listing of a collectible can be bought until block `until`
+        ListingExpirySet { collectible: [u8;16], until: BlockNumberFor<T> },
+        /// A listing will switch to `price` at block `at`
+        PriceScheduled { collectible: [u8;16], price: BalanceOf<T, I>, at: BlockNumberFor<T> },
+        /// A creator set how long their collectibles can't be listed after changing hands, `None` lifting it
+        RelistCooldownSet { creator: T::AccountId, cooldown: Option<BlockNumberFor<T>> },
+        /// A collectible was listed at `price`, dropping by `decrement` every block down to `floor`
+        DecliningPriceSet {
+            collectible: [u8;16],
+            price: BalanceOf<T, I>,
+            decrement: BalanceOf<T, I>,
+            floor: BalanceOf<T, I>
+        },
+        /// The owner of a collectible has airdropped it to `recipient`, who can claim it
+        Airdropped { collectible: [u8;16], from: T::AccountId, recipient: T::AccountId },
+        /// The recipient of an airdrop has claimed it
+        AirdropClaimed { collectible: [u8;16], recipient: T::AccountId },
+        /// The owner of an airdropped collectible has cancelled the airdrop
+        AirdropCancelled { collectible: [u8;16] },
+        /// `sponsor` submitted a transfer pre-signed by the collectible's owner
+        SponsoredTransfer { collectible: [u8;16], sponsor: T::AccountId },
+        /// The owner of a collectible wrote a call option on it
+        OptionWritten {
+            collectible: [u8;16],
+            writer: T::AccountId,
+            premium: BalanceOf<T, I>,
+            strike: BalanceOf<T, I>,
+            expiry: BlockNumberFor<T>
+        },
+        /// `holder` bought a call option, paying its premium to the writer
+        OptionBought { collectible: [u8;16], holder: T::AccountId },
+        /// The holder of a call option exercised it, buying the collectible at the strike price
+        OptionExercised { collectible: [u8;16], holder: T::AccountId },
+        /// The writer of a call option closed it, unsold or expired, unlocking the collectible
+        OptionClosed { collectible: [u8;16] },
+        /// A collectible was delivered to its buyer, with its price in escrow until `release_at`
+        EscrowOpened {
+            collectible: [u8;16],
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            price: BalanceOf<T, I>,
+            release_at: BlockNumberFor<T>
+        },
+        /// The price of an escrowed sale was released to the seller
+        EscrowReleased { collectible: [u8;16] },
+        /// The buyer of an escrowed sale disputed it
+        EscrowDisputed { collectible: [u8;16] },
+        /// The arbitrator refunded a disputed sale, giving the collectible back to the seller, or released it
+        DisputeResolved { collectible: [u8;16], refunded: bool },
+        /// The owner of a collectible gifted it to `recipient`, who can claim it until `claim_deadline`
+        Gifted { collectible: [u8;16], from: T::AccountId, recipient: T::AccountId, claim_deadline: BlockNumberFor<T> },
+        /// The recipient of a gift claimed it
+        GiftClaimed { collectible: [u8;16], recipient: T::AccountId },
+        /// An unclaimed gift reverted to its sender
+        GiftReverted { collectible: [u8;16] },
+        /// A fan tipped the creator side of a collectible
+        CreatorTipped { collectible: [u8;16], from: T::AccountId, to: T::AccountId, amount: BalanceOf<T, I> },
+        /// The owner of a collectible named it
+        NameSet { collectible: [u8;16], name: BoundedVec<u8, T::NameLimit> },
+        /// A collectible lost its name, cleared by its owner or on a transfer
+        NameCleared { collectible: [u8;16] },
+        /// The owner of a collectible set their note on it
+        NoteSet { collectible: [u8;16], owner: T::AccountId, note: BoundedVec<u8, T::NoteLimit> },
+        /// An owner's note on a collectible was cleared, by them or on a transfer
+        NoteCleared { collectible: [u8;16], owner: T::AccountId },
+        /// The owner of a collectible paused or resumed trading it
+        TradingPauseSet { collectible: [u8;16], paused: bool },
+        /// The holders of every collectible were recorded under `root`
+        HoldersSnapshotted { snapshot: u32, at: BlockNumberFor<T>, root: T::Hash },
+        /// A pallet of the runtime minted a collectible to `to` as a reward
+        CollectibleRewarded { collectible: [u8;16], minter: PalletId, to: T::AccountId },
+        /// Governance set how many more collectibles a pallet can mint as rewards
+        RewardQuotaSet { minter: PalletId, quota: u32 },
+        /// The creator of a collectible allowed `who` to hold it, restricting it to its allowed holders
+        HolderAllowed { collectible: [u8;16], who: T::AccountId },
+        /// The creator of a collectible removed `who` from its allowed holders
+        HolderDisallowed { collectible: [u8;16], who: T::AccountId },
+        /// The owner of a listing set the condition its buyers must meet, `None` lifting it
+        ListingConditionSet {
+            collectible: [u8;16],
+            condition: Option<<T::BuyerQualification as BuyerQualification<T::AccountId>>::Condition>
+        }
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+        /// Collectibles to create, with their owner, id and color. No deposit is held for them, but owners can't
+        /// exceed `MaximumOwned`
+        pub collectibles: Vec<(T::AccountId, [u8; 16], Color)>,
+        /// Creators vetted from the start
+        pub verified_creators: Vec<T::AccountId>,
+        pub minters_need_identity: bool,
+        pub buyers_need_identity: bool,
+        /// Overrides the `MaximumOwned` constant if set, `Some(None)` lifting the cap
+        pub maximum_owned: Option<Option<u32>>,
+        /// The part of each sale price burned
+        pub sale_burn: Perbill,
+        /// Default royalties of creators, within `MaxRoyalty` and `royalty_range`
+        pub default_royalties: Vec<(T::AccountId, Royalty<T::AccountId>)>,
+        /// The range creators' royalty rates must fall in
+        pub royalty_range: Option<(Permill, Permill)>,
+        #[serde(skip)]
+        pub phantom: PhantomData<I>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config<I>, I: 'static> frame_support::traits::BuildGenesisConfig for GenesisConfig<T, I> {
+        fn build(&self) {
+            MintersNeedIdentity::<T, I>::put(self.minters_need_identity);
+            BuyersNeedIdentity::<T, I>::put(self.buyers_need_identity);
+            if let Some(maximum_owned) = self.maximum_owned {
+                MaximumOwned::<T, I>::put(maximum_owned);
+            }
+            SaleBurn::<T, I>::put(self.sale_burn);
+            if let Some((lowest, highest)) = self.royalty_range {
+                assert!(
+                    lowest <= highest && highest <= T::MaxRoyalty::get(),
+                    "Genesis royalty range {:?} is inverted or above MaxRoyalty",
+                    (lowest, highest)
+                );
+                RoyaltyRange::<T, I>::put((lowest, highest));
+            }
+            for (creator, royalty) in &self.default_royalties {
+                assert!(
+                    Pallet::<T, I>::ensure_royalty_allowed(&Some(royalty.clone())).is_ok(),
+                    "Genesis default royalty of {:?} is above MaxRoyalty or outside the royalty range",
+                    creator
+                );
+                assert!(
+                    !DefaultRoyalties::<T, I>::contains_key(creator),
+                    "Genesis default royalty of {:?} is set twice",
+                    creator
+                );
+                DefaultRoyalties::<T, I>::insert(creator, royalty);
+            }
+            for creator in &self.verified_creators {
+                VerifiedCreators::<T, I>::insert(creator, ());
+            }
+            for (owner, unique_id, color) in &self.collectibles {
+                assert!(
+                    !CollectibleMap::<T, I>::contains_key(unique_id),
+                    "Genesis collectible {:?} is listed twice",
+                    unique_id
+                );
+                assert!(
+                    !T::EnumerateOwnership::get()
+                        || MaximumOwned::<T, I>::get()
+                            .map_or(true, |maximum_owned| OwnedCount::<T, I>::get(owner) < maximum_owned),
+                    "Genesis collectible {:?} takes {:?} over MaximumOwned",
+                    unique_id,
+                    owner
+                );
+                let imported = Pallet::<T, I>::import(Collectible::<T, I> {
+                    unique_id: *unique_id,
+                    price: None,
+                    color: *color,
+                    owner: owner.clone(),
+                    creator: owner.clone(),
+                });
+                assert!(imported.is_ok(), "Genesis collectible {:?} couldn't be imported: {:?}", unique_id, imported);
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I>{
+        // Due transfers and gifts wait for an ongoing migration, as they read the half-migrated collectibles
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight{
+            if OngoingMigration::<T, I>::exists(){
+                return T::DbWeight::get().reads(1);
+            }
+            Self::execute_scheduled_transfers(n).saturating_add(Self::revert_expired_gifts(n))
+        }
+
+        // Submit the settlement of ended buyouts, so winners get their collectibles promptly, and the delisting of
+        // expired listings, so they don't linger until someone tries to buy them. Only the entries due from the block
+        // the cursor points at on are read. They stay indexed until settled or delisted on chain, so the cursor only
+        // moves past the blocks left empty, and entries whose transaction didn't make it into a block are submitted again
+        fn offchain_worker(n: BlockNumberFor<T>){
+            if OngoingMigration::<T, I>::exists(){
+                return;
+            }
+            let cursor = StorageValueRef::persistent(&Self::due_cursor_key());
+            let mut block = cursor.get::<BlockNumberFor<T>>().ok().flatten().unwrap_or(n);
+            let limit = T::MaxUnsignedPerBlock::get() as usize;
+            let mut submitted = 0;
+            let mut first_pending = None;
+            let mut scanned = 0;
+            while block <= n && submitted < limit && scanned < MAX_SCANNED_BLOCKS{
+                let ended = EndingBuyouts::<T, I>::iter_key_prefix(block)
+                    .map(|collectible_id| Call::<T, I>::settle_buyout_unsigned { collectible_id });
+                let expired = ExpiringListings::<T, I>::iter_key_prefix(block)
+                    .map(|collectible_id| Call::<T, I>::delist_expired { collectible_id });
+                for call in ended.chain(expired).take(limit - submitted){
+                    first_pending.get_or_insert(block);
+                    let _ = SubmitTransaction::<T, Call<T, I>>::submit_unsigned_transaction(call.into());
+                    submitted += 1;
+                }
+                block = block.saturating_add(One::one());
+                scanned += 1;
+            }
+            cursor.set(&first_pending.unwrap_or(block));
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight{
+            let consumed = crate::migrations::v1::step::<T, I>(remaining_weight).saturating_add(T::DbWeight::get().reads(1));
+            // Rentals are only expired once the collectibles are migrated
+            if OngoingMigration::<T, I>::exists(){
+                return consumed;
+            }
+            consumed.saturating_add(Self::expire_rentals(n, remaining_weight.saturating_sub(consumed)))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError>{
+            Self::do_try_state()
+        }
+
+        // Catches upgrades shipped without the migration matching the new storage version
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError>{
+            if OngoingMigration::<T, I>::exists(){
+                return Ok(());
+            }
+            ensure!(
+                Pallet::<T, I>::on_chain_storage_version() == STORAGE_VERSION,
+                "The on-chain storage version doesn't match the pallet's, a migration is missing"
+            );
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        /// Invariants of the pallet storage. They don't hold while a multi-block migration is ongoing
+        pub fn do_try_state() -> Result<(), TryRuntimeError>{
+            if OngoingMigration::<T, I>::exists(){
+                return Ok(());
+            }
+
+            // The counter of the collectibles map matches its entries
+            let collectibles = CollectibleMap::<T, I>::iter_keys().count() as u32;
+            ensure!(CollectibleMap::<T, I>::count() == collectibles, "The total supply doesn't match the collectibles");
+
+            let enumerate = T::EnumerateOwnership::get();
+            if enumerate{
+                // Every collectible is indexed under its owner, and nowhere else
+                for collectible in CollectibleMap::<T, I>::iter_values(){
+                    ensure!(
+                        OwnerOfCollectibles::<T, I>::contains_key(&collectible.owner, collectible.unique_id),
+                        "A collectible isn't indexed under its owner"
+                    );
+                }
+                ensure!(
+                    OwnerOfCollectibles::<T, I>::iter_keys().count() as u32 == collectibles,
+                    "The ownership index has entries for collectibles that don't exist or aren't owned by that account"
+                );
+            }
+            else{
+                ensure!(OwnerOfCollectibles::<T, I>::iter_keys().next().is_none(), "The ownership index is disabled but has entries");
+            }
+
+            // The per-account and holders counters match the index, and only holders have a count
+            let mut holders = 0u32;
+            let mut counted = 0u32;
+            for (owner, owned) in OwnedCount::<T, I>::iter(){
+                ensure!(owned > 0, "An account without collectibles still has an owned count");
+                ensure!(
+                    !enumerate || OwnerOfCollectibles::<T, I>::iter_key_prefix(&owner).count() as u32 == owned,
+                    "An owned count doesn't match the ownership index"
+                );
+                holders += 1;
+                counted = counted.saturating_add(owned);
+            }
+            ensure!(counted == collectibles, "The owned counts don't add up to the total supply");
+            ensure!(HoldersCount::<T, I>::get() == holders, "The holders count doesn't match the owners");
+
+            // The color indexes match the collectibles
+            for (color, supply) in ColorSupply::<T, I>::iter(){
+                ensure!(
+                    CollectiblesByColor::<T, I>::iter_key_prefix(color).count() as u32 == supply,
+                    "A color supply doesn't match the color index"
+                );
+            }
+            ensure!(
+                ColorSupply::<T, I>::iter_values().fold(0u32, |total, supply| total.saturating_add(supply)) == collectibles,
+                "The color supplies don't add up to the total supply"
+            );
+            ensure!(
+                CollectiblesByColor::<T, I>::iter_keys().all(|(color, collectible_id)| {
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.color == color)
+                }),
+                "The color index refers to a collectible that doesn't exist or has another color"
+            );
+
+            // Flags and locks only refer to existing collectibles
+            ensure!(
+                LockedCollectibles::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A lock refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                StolenCollectibles::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A stolen flag refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                FlaggedCollectibles::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A moderation flag refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                LastTouched::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A touch marker refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                ItemDeposits::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A deposit refers to a collectible that doesn't exist"
+            );
+            for collectible_id in ListingAssets::<T, I>::iter_keys(){
+                ensure!(
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some()),
+                    "A listing asset refers to a collectible that isn't on sale"
+                );
+            }
+            ensure!(
+                Fractionalized::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Fractionalized)),
+                "A fractionalized collectible isn't locked"
+            );
+            ensure!(
+                Buyouts::<T, I>::iter_keys().all(|collectible_id| Fractionalized::<T, I>::contains_key(collectible_id)),
+                "A buyout refers to a collectible that isn't fractionalized"
+            );
+            ensure!(
+                Backing::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A backing refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                Loans::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Loan)),
+                "The collateral of a loan isn't locked"
+            );
+            ensure!(
+                Rentals::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Rental)),
+                "A rented collectible isn't locked"
+            );
+            ensure!(
+                RentalOffers::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A rental offer refers to a collectible that doesn't exist"
+            );
+            let queued = RentalExpiries::<T, I>::iter_keys().count() as u32;
+            ensure!(QueuedRentalExpiries::<T, I>::get() == queued, "The queued rental expiries count doesn't match the queue");
+            ensure!(
+                Rentals::<T, I>::iter().all(|(collectible_id, (_, until))| {
+                    RentalExpiries::<T, I>::contains_key(until.saturating_add(One::one()), collectible_id)
+                }),
+                "A rental isn't queued to expire"
+            );
+            ensure!(
+                RentStreams::<T, I>::iter_keys().all(|collectible_id| Rentals::<T, I>::contains_key(collectible_id)),
+                "A rent stream refers to a collectible that isn't rented"
+            );
+            ensure!(
+                Stakes::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Staked)),
+                "A staked collectible isn't locked"
+            );
+            ensure!(
+                RevenueCheckpoints::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A revenue checkpoint refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                Royalties::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A royalty refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                Royalties::<T, I>::iter_values().all(|royalty| royalty.rate <= T::MaxRoyalty::get()),
+                "A royalty is above MaxRoyalty"
+            );
+            ensure!(
+                Parked::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Parked)),
+                "A parked collectible isn't locked"
+            );
+            ensure!(
+                Exported::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Exported)),
+                "An exported collectible isn't locked"
+            );
+            for (collectible_id, (_, at)) in ScheduledTransfers::<T, I>::iter(){
+                ensure!(
+                    LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::ScheduledTransfer),
+                    "A collectible scheduled for transfer isn't locked"
+                );
+                ensure!(
+                    ScheduledTransferQueue::<T, I>::get(at).contains(&collectible_id),
+                    "A scheduled transfer isn't queued at its block"
+                );
+            }
+            ensure!(
+                Approvals::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "An approval refers to a collectible that doesn't exist"
+            );
+            for (collectible_id, _) in CoOwned::<T, I>::iter(){
+                ensure!(
+                    Self::owner_of(collectible_id) == Some(Self::co_ownership_account(collectible_id)),
+                    "A co-owned collectible isn't held by its co-ownership account"
+                );
+            }
+            ensure!(
+                DisposalApprovals::<T, I>::iter_keys().all(|collectible_id| CoOwned::<T, I>::contains_key(collectible_id)),
+                "A disposal is being approved for a collectible that isn't co-owned"
+            );
+            ensure!(
+                ListingExpiries::<T, I>::iter_keys().all(|collectible_id| {
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some())
+                }),
+                "A listing expiry refers to a collectible that isn't listed"
+            );
+            // The offchain worker's indexes match the expiries and buyouts
+            ensure!(
+                ExpiringListings::<T, I>::iter_keys().all(|(block, collectible_id)| {
+                    ListingExpiries::<T, I>::get(collectible_id).map_or(false, |until| until.saturating_add(One::one()) == block)
+                }) && ExpiringListings::<T, I>::iter_keys().count() == ListingExpiries::<T, I>::iter_keys().count(),
+                "The expiring listings index doesn't match the listing expiries"
+            );
+            ensure!(
+                EndingBuyouts::<T, I>::iter_keys().all(|(block, collectible_id)| {
+                    Buyouts::<T, I>::get(collectible_id).map_or(false, |buyout| buyout.end.saturating_add(One::one()) == block)
+                }) && EndingBuyouts::<T, I>::iter_keys().count() == Buyouts::<T, I>::iter_keys().count(),
+                "The ending buyouts index doesn't match the buyouts"
+            );
+            ensure!(
+                Acquisitions::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "An acquisition refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                ScheduledPrices::<T, I>::iter_keys().all(|collectible_id| {
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some())
+                }),
+                "A scheduled price refers to a collectible that isn't listed"
+            );
+            ensure!(
+                DecliningPrices::<T, I>::iter().all(|(collectible_id, (_, floor, _))| {
+                    CollectibleMap::<T, I>::get(collectible_id)
+                        .and_then(|collectible| collectible.price)
+                        .map_or(false, |price| floor <= price)
+                }),
+                "A declining price refers to a collectible that isn't listed, or its floor is above its price"
+            );
+            ensure!(
+                ListingApprovals::<T, I>::iter_keys().all(|collectible_id| CoOwned::<T, I>::contains_key(collectible_id)),
+                "A listing is being approved for a collectible that isn't co-owned"
+            );
+            ensure!(
+                DelistingApprovals::<T, I>::iter_keys().all(|collectible_id| CoOwned::<T, I>::contains_key(collectible_id)),
+                "A delisting is being approved for a collectible that isn't co-owned"
+            );
+            ensure!(
+                Escrows::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Escrow)),
+                "A collectible in escrow isn't locked"
+            );
+            ensure!(
+                CallOptions::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::CallOption)),
+                "A collectible with a call option isn't locked"
+            );
+            for (collectible_id, (_, _, deadline)) in Gifts::<T, I>::iter(){
+                ensure!(
+                    LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Gift),
+                    "A gifted collectible isn't locked"
+                );
+                ensure!(
+                    GiftExpiryQueue::<T, I>::get(deadline.saturating_add(One::one())).contains(&collectible_id),
+                    "A gift isn't queued to revert after its claim deadline"
+                );
+            }
+            for (collectible_id, (name, _)) in Names::<T, I>::iter(){
+                ensure!(CollectibleMap::<T, I>::contains_key(collectible_id), "A name refers to a collectible that doesn't exist");
+                ensure!(
+                    !T::UniqueNames::get() || NamedCollectibles::<T, I>::get(&name) == Some(collectible_id),
+                    "A unique name isn't indexed"
+                );
+            }
+            ensure!(
+                NamedCollectibles::<T, I>::iter().all(|(name, collectible_id)| {
+                    Names::<T, I>::get(collectible_id).map_or(false, |(own_name, _)| own_name == name)
+                }),
+                "An indexed name isn't held by its collectible"
+            );
+            ensure!(
+                ListingConditions::<T, I>::iter_keys().all(|collectible_id| {
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some())
+                }),
+                "A listing condition refers to a collectible that isn't listed"
+            );
+            ensure!(
+                AllowedHolders::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A restricted collectible doesn't exist"
+            );
+            ensure!(
+                TradingPaused::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A paused collectible doesn't exist"
+            );
+            ensure!(
+                Notes::<T, I>::iter_keys().all(|(owner, collectible_id)| Self::owner_of(collectible_id) == Some(owner)),
+                "A note is kept by an account that doesn't own the collectible"
+            );
+            // Every owner holds exactly the deposits of its collectibles, of their names and of its notes
+            let mut deposits = sp_std::collections::btree_map::BTreeMap::<T::AccountId, [BalanceOf<T, I>; 3]>::new();
+            for collectible in CollectibleMap::<T, I>::iter_values(){
+                let held = deposits.entry(collectible.owner).or_insert([Zero::zero(); 3]);
+                if let Some(deposit) = ItemDeposits::<T, I>::get(collectible.unique_id){
+                    held[0] = held[0].saturating_add(deposit);
+                }
+                if let Some((_, deposit)) = Names::<T, I>::get(collectible.unique_id){
+                    held[1] = held[1].saturating_add(deposit);
+                }
+            }
+            for (owner, _, (_, deposit)) in Notes::<T, I>::iter(){
+                let held = deposits.entry(owner).or_insert([Zero::zero(); 3]);
+                held[2] = held[2].saturating_add(deposit);
+            }
+            for (owner, [item, name, note]) in deposits{
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::ItemDeposit.into(), &owner) == item,
+                    "An account's held item deposits don't match its collectibles"
+                );
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::NameDeposit.into(), &owner) == name,
+                    "An account's held name deposits don't match the names of its collectibles"
+                );
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::NoteDeposit.into(), &owner) == note,
+                    "An account's held note deposits don't match its notes"
+                );
+            }
+            ensure!(
+                Airdrops::<T, I>::iter_keys()
+                    .all(|collectible_id| LockedCollectibles::<T, I>::get(collectible_id) == Some(LockReason::Airdrop)),
+                "An airdropped collectible isn't locked"
+            );
+            ensure!(
+                Managers::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A manager refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                Metadata::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "Metadata refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                ContentHashes::<T, I>::iter_keys().all(|collectible_id| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "A content hash refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                Attributes::<T, I>::iter_keys().all(|(collectible_id, _)| CollectibleMap::<T, I>::contains_key(collectible_id)),
+                "An attribute refers to a collectible that doesn't exist"
+            );
+            ensure!(
+                RentToOwnTerms::<T, I>::iter_keys().all(|collectible_id| RentalOffers::<T, I>::contains_key(collectible_id)),
+                "Rent-to-own terms refer to a collectible that isn't offered for rent"
+            );
+            for collectible_id in AssetQuotes::<T, I>::iter_keys(){
+                ensure!(
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some()),
+                    "Asset quotes refer to a collectible that isn't on sale"
+                );
+            }
+            for collectible_id in ReferencePriced::<T, I>::iter_keys(){
+                ensure!(
+                    CollectibleMap::<T, I>::get(collectible_id).map_or(false, |collectible| collectible.price.is_some()),
+                    "A reference price marker refers to a collectible that isn't on sale"
+                );
+                ensure!(
+                    !ListingAssets::<T, I>::contains_key(collectible_id),
+                    "A collectible is priced both in an asset and in the reference unit"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        // User-facing calls are rejected while the pallet is paused or its storage is half migrated
+        pub(crate) fn ensure_not_paused() -> DispatchResult{
+            ensure!(!Paused::<T, I>::get(), Error::<T, I>::PalletPaused);
+            Self::ensure_migrated()
+        }
+
+        // Half-migrated collectibles can't be decoded, so even the calls allowed while paused wait for the migration
+        pub(crate) fn ensure_migrated() -> DispatchResult{
+            ensure!(!OngoingMigration::<T, I>::exists(), Error::<T, I>::MigrationOngoing);
+            Ok(())
+        }
+
+        pub(crate) fn gen_unique_id() -> ([u8;16], Color){
+            let random = T::Randomness::random(&b"unique_id"[..]).0;
+            let nonce = IdNonce::<T, I>::mutate(|nonce| {
+                *nonce = nonce.wrapping_add(1);
+                *nonce
+            });
+
+            let unique_payload = (
+                random,
+                frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+                frame_system::Pallet::<T>::block_number(),
+                nonce
+            );
+
+            let encode_payload = unique_payload.encode();
+            let hash = frame_support::Hashable::blake2_128(&encode_payload);
+            if hash[0]%2 == 0 {
+                (hash, Color::Red)
+            }
+            else{
+                (hash, Color::Yellow)
+            }
+        }
+
+        pub(crate) fn mint(
+            owner: &T::AccountId,
+            unique_id: [u8;16],
+            color: Color
+        ) -> Result<[u8;16],DispatchError>{
+            let collectible = Collectible::<T, I> {
+                unique_id,
+                price: None,
+                color,
+                owner: owner.clone(),
+                creator: owner.clone()
+            };
+
+            ensure!(!CollectibleMap::<T, I>::contains_key(&unique_id), Error::<T, I>::DuplicateCollectible);
+            ensure!(CollectibleMap::<T, I>::count() < u32::MAX, Error::<T, I>::BoundsOverflow);
+            ensure!(
+                !MintersNeedIdentity::<T, I>::get() || T::Identity::has_judged_identity(owner),
+                Error::<T, I>::NoJudgedIdentity
+            );
+
+            Self::add_to_owner(owner, unique_id)?;
+
+            let deposit = ItemDeposit::<T, I>::get();
+            if !deposit.is_zero(){
+                T::Currency::hold(&HoldReason::ItemDeposit.into(), owner, deposit)?;
+                ItemDeposits::<T, I>::insert(unique_id, deposit);
+            }
+
+            Self::index_color(unique_id, color);
+            CollectibleMap::<T, I>::insert(unique_id, collectible);
+            // Only revenue deposited from now on is shared with the new collectible
+            let revenue_per_item = RevenuePerItem::<T, I>::get();
+            if !revenue_per_item.is_zero(){
+                RevenueCheckpoints::<T, I>::insert(unique_id, revenue_per_item);
+            }
+
+            Self::deposit_event(Event::CollectibleCreated {
+                collectible: unique_id,
+                owner: owner.clone(),
+                color,
+                verified: VerifiedCreators::<T, I>::contains_key(owner)
+            });
+
+            Ok(unique_id)
+        }
+
+        // Insert a collectible without holding a deposit or enforcing `MaximumOwned`, e.g. when brought over from another
+        // NFT pallet, where its owner already held it, or handed out by the faucet
+        pub(crate) fn import(collectible: Collectible<T, I>) -> DispatchResult{
+            let unique_id = collectible.unique_id;
+            ensure!(!CollectibleMap::<T, I>::contains_key(&unique_id), Error::<T, I>::DuplicateCollectible);
+            ensure!(CollectibleMap::<T, I>::count() < u32::MAX, Error::<T, I>::BoundsOverflow);
+
+            let owned = OwnedCount::<T, I>::get(&collectible.owner).checked_add(1).ok_or(Error::<T, I>::BoundsOverflow)?;
+            if T::EnumerateOwnership::get(){
+                OwnerOfCollectibles::<T, I>::insert(&collectible.owner, unique_id, ());
+            }
+            OwnedCount::<T, I>::insert(&collectible.owner, owned);
+            if owned == 1{
+                HoldersCount::<T, I>::mutate(|count| *count = count.defensive_saturating_add(1));
+            }
+
+            let revenue_per_item = RevenuePerItem::<T, I>::get();
+            if !revenue_per_item.is_zero(){
+                RevenueCheckpoints::<T, I>::insert(unique_id, revenue_per_item);
+            }
+            Self::index_color(unique_id, collectible.color);
+            CollectibleMap::<T, I>::insert(unique_id, collectible);
+            Ok(())
+        }
+
+        // Count and index a new collectible under its color
+        fn index_color(collectible_id: [u8; 16], color: Color){
+            ColorSupply::<T, I>::mutate(color, |supply| *supply = supply.defensive_saturating_add(1));
+            CollectiblesByColor::<T, I>::insert(color, collectible_id, ());
+        }
+
+        // The id of an imported `pallet_nfts` item: the item id itself if it fits and is free, `blake2_128((collection,
+        // item))` otherwise
+        fn imported_id(collection: &T::NftsCollectionId, item: &T::NftsItemId) -> [u8; 16]{
+            let encoded_item = item.encode();
+            if encoded_item.len() <= 16{
+                let mut collectible_id = [0u8; 16];
+                collectible_id[..encoded_item.len()].copy_from_slice(&encoded_item);
+                if !CollectibleMap::<T, I>::contains_key(&collectible_id){
+                    return collectible_id;
+                }
+            }
+            (collection, item).using_encoded(sp_io::hashing::blake2_128)
+        }
+
+        // Unwind whatever holds a collectible's lock, refunding the funds held for it, so it can be burnt. Shares of a
+        // fractionalized collectible stay with their holders, backed by nothing
+        fn release_lock(collectible_id: [u8; 16]) -> DispatchResult{
+            let Some(reason) = LockedCollectibles::<T, I>::get(&collectible_id) else {
+                return Ok(());
+            };
+            match reason{
+                LockReason::External(_) => (),
+                LockReason::Fractionalized => {
+                    Fractionalized::<T, I>::remove(collectible_id);
+                    if let Some(buyout) = Buyouts::<T, I>::take(&collectible_id){
+                        EndingBuyouts::<T, I>::remove(buyout.end.saturating_add(One::one()), collectible_id);
+                        T::Currency::transfer(&Self::account_id(), &buyout.bidder, buyout.bid, Preservation::Expendable)?;
+                    }
+                },
+                LockReason::Loan => Loans::<T, I>::remove(collectible_id),
+                LockReason::Rental => {
+                    if let Some((renter, _)) = Rentals::<T, I>::get(&collectible_id){
+                        // Closing the rental releases the lock itself
+                        return Self::close_rental(collectible_id, renter);
+                    }
+                },
+                LockReason::Staked => {
+                    if let Some((staker, since)) = Stakes::<T, I>::get(&collectible_id){
+                        let _ = with_storage_layer(|| Self::pay_staking_rewards(collectible_id, &staker, since));
+                        // Paying the rewards checkpoints the stake again
+                        Stakes::<T, I>::remove(collectible_id);
+                    }
+                },
+                LockReason::Parked => Parked::<T, I>::remove(collectible_id),
+                LockReason::Exported => Exported::<T, I>::remove(collectible_id),
+                LockReason::ScheduledTransfer => {
+                    if let Some((_, at)) = ScheduledTransfers::<T, I>::take(&collectible_id){
+                        ScheduledTransferQueue::<T, I>::mutate(at, |queued| queued.retain(|queued_id| *queued_id != collectible_id));
+                    }
+                },
+                LockReason::CallOption => CallOptions::<T, I>::remove(collectible_id),
+                LockReason::Escrow => {
+                    if let Some(escrow) = Escrows::<T, I>::take(&collectible_id){
+                        T::Currency::transfer(
+                            &Self::escrow_account(collectible_id),
+                            &escrow.buyer,
+                            escrow.price,
+                            Preservation::Expendable
+                        )?;
+                    }
+                },
+                LockReason::Gift => {
+                    if let Some((_, _, deadline)) = Gifts::<T, I>::take(&collectible_id){
+                        GiftExpiryQueue::<T, I>::mutate(deadline.saturating_add(One::one()), |queued| {
+                            queued.retain(|queued_id| *queued_id != collectible_id)
+                        });
+                    }
+                },
+                LockReason::Airdrop => Airdrops::<T, I>::remove(collectible_id),
+            }
+            Self::unlock_for(collectible_id, reason)
+        }
+
+        // Remove a collectible and every index pointing to it. Returns whether its item deposit was released, how many
+        // attributes it had and the length of its metadata
+        fn burn(collectible: &Collectible<T, I>) -> Result<(bool, u32, u32), DispatchError>{
+            let collectible_id = collectible.unique_id;
+            ensure!(!LockedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleLocked);
+
+            // Remove the collectible from the map
+            CollectibleMap::<T, I>::remove(&collectible_id);
+            ColorSupply::<T, I>::mutate(collectible.color, |supply| *supply = supply.defensive_saturating_sub(1));
+            CollectiblesByColor::<T, I>::remove(collectible.color, collectible_id);
+            StolenCollectibles::<T, I>::remove(&collectible_id);
+            FlaggedCollectibles::<T, I>::remove(&collectible_id);
+            LastTouched::<T, I>::remove(&collectible_id);
+            Self::clear_price_unit(collectible_id);
+            Self::clear_rental_offer(collectible_id);
+            Self::settle_revenue(collectible_id, &collectible.owner);
+            RevenueCheckpoints::<T, I>::remove(collectible_id);
+            LastSalePrice::<T, I>::remove(collectible_id);
+            Royalties::<T, I>::remove(collectible_id);
+            Approvals::<T, I>::remove(collectible_id);
+            Managers::<T, I>::remove(collectible_id);
+            CoOwned::<T, I>::remove(collectible_id);
+            DisposalApprovals::<T, I>::remove(collectible_id);
+            ListingApprovals::<T, I>::remove(collectible_id);
+            DelistingApprovals::<T, I>::remove(collectible_id);
+            Acquisitions::<T, I>::remove(collectible_id);
+            TradingPaused::<T, I>::remove(collectible_id);
+            AllowedHolders::<T, I>::remove(collectible_id);
+            ContentHashes::<T, I>::remove(collectible_id);
+            let metadata = Metadata::<T, I>::take(collectible_id).map_or(0, |metadata| metadata.len() as u32);
+            if metadata > 0{
+                Self::deposit_event(Event::MetadataCleared { collectible: collectible_id });
+            }
+            Self::remove_name(collectible_id, &collectible.owner)?;
+            Self::remove_note(collectible_id, &collectible.owner)?;
+            let mut attributes = 0;
+            for (key, _) in Attributes::<T, I>::drain_prefix(collectible_id).take(T::MaxImportedAttributes::get() as usize){
+                attributes += 1;
+                Self::deposit_event(Event::AttributeCleared { collectible: collectible_id, key });
+            }
+            let deposit = ItemDeposits::<T, I>::take(&collectible_id);
+            if let Some(deposit) = deposit{
+                T::Currency::release(&HoldReason::ItemDeposit.into(), &collectible.owner, deposit, Precision::BestEffort)?;
+            }
+            // The backing is the floor value of the collectible, it goes to whoever owns it when it's destroyed
+            if let Some(backing) = Backing::<T, I>::take(&collectible_id){
+                T::Currency::transfer(&Self::backing_account(collectible_id), &collectible.owner, backing, Preservation::Expendable)?;
+            }
+
+            Self::remove_from_owner(&collectible.owner, collectible_id);
+            BurnedCount::<T, I>::mutate(|burned| *burned = burned.saturating_add(1));
+            Ok((deposit.is_some(), attributes, metadata))
+        }
+
+        // Index a collectible under its new owner, enforcing the `MaximumOwned` parameter
+        fn add_to_owner(owner: &T::AccountId, collectible_id: [u8; 16]) -> DispatchResult{
+            if T::EnumerateOwnership::get(){
+                ensure!(
+                    MaximumOwned::<T, I>::get().map_or(true, |maximum_owned| OwnedCount::<T, I>::get(owner) < maximum_owned),
+                    Error::<T, I>::MaximumCollectiblesOwned
+                );
+            }
+            Self::index_owner(owner, collectible_id)
+        }
+
+        // The most collectibles a transfer recipient is weighed as owning already
+        pub(crate) fn max_weighed_owned() -> u32{
+            T::MaximumOwned::get().map_or(MAX_WEIGHED_OWNED, |maximum_owned| maximum_owned.min(MAX_WEIGHED_OWNED))
+        }
+
+        // Index a collectible under its new owner, however many they own
+        fn index_owner(owner: &T::AccountId, collectible_id: [u8; 16]) -> DispatchResult{
+            let new_owned = OwnedCount::<T, I>::get(owner).checked_add(1).ok_or(Error::<T, I>::BoundsOverflow)?;
+            if T::EnumerateOwnership::get(){
+                OwnerOfCollectibles::<T, I>::insert(owner, collectible_id, ());
+            }
+            OwnedCount::<T, I>::insert(owner, new_owned);
+            // Keep track of accounts that just became holders
+            if new_owned == 1{
+                HoldersCount::<T, I>::mutate(|count| *count = count.defensive_saturating_add(1));
+            }
+            Ok(())
+        }
+
+        // Remove a collectible from the index of its former owner
+        fn remove_from_owner(owner: &T::AccountId, collectible_id: [u8; 16]){
+            if T::EnumerateOwnership::get(){
+                OwnerOfCollectibles::<T, I>::remove(owner, collectible_id);
+            }
+            // The owner holds at least this collectible, anything else is a bug elsewhere
+            let owned = OwnedCount::<T, I>::get(owner).defensive_saturating_sub(1);
+            // Keep track of accounts that just stopped being holders, and drop their entry
+            if owned == 0{
+                OwnedCount::<T, I>::remove(owner);
+                HoldersCount::<T, I>::mutate(|count| *count = count.defensive_saturating_sub(1));
+            }
+            else{
+                OwnedCount::<T, I>::insert(owner, owned);
+            }
+        }
+
+        // Update storage to transfer collectible.
+        // Other pallets may call it outside of an extrinsic, so it runs in its own storage layer
+        pub fn do_transfer(
+            collectible_id: [u8; 16],
+            to: T::AccountId,
+        ) -> DispatchResult {
+            with_storage_layer(|| {
+                ensure!(TransfersEnabled::<T, I>::get(), Error::<T, I>::TransfersDisabled);
+                ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+                let (collectible, from) = Self::pre_transfer(collectible_id, &to)?;
+                Self::post_transfer(&collectible, &from, &to)?;
+                Self::deposit_event(Event::TransferSucceeded { from, to, collectible: collectible_id, color: collectible.color });
+                Ok(())
+            })
+        }
+
+        // Split `proceeds` among the co-owners by their shares. Rounding leftovers go to the last one
+        fn pay_co_owners(
+            asset: Option<AssetIdOf<T, I>>,
+            from: &T::AccountId,
+            co_ownership: &CoOwnership<T, I>,
+            proceeds: BalanceOf<T, I>,
+            preservation: Preservation
+        ) -> DispatchResult{
+            let total_shares: u32 = co_ownership.owners.iter().map(|(_, shares)| *shares).sum();
+            let mut left = proceeds;
+            for (index, (co_owner, shares)) in co_ownership.owners.iter().enumerate(){
+                let last = index + 1 == co_ownership.owners.len();
+                let payout = if last{
+                    left
+                }
+                else{
+                    multiply_by_rational_with_rounding(
+                        proceeds.saturated_into(),
+                        (*shares).into(),
+                        total_shares.into(),
+                        Rounding::Down
+                    ).map(|payout| payout.saturated_into()).unwrap_or_else(Zero::zero)
+                };
+                Self::pay(asset, from, co_owner, payout, if last { preservation } else { Preservation::Preserve })?;
+                left = left.saturating_sub(payout);
+            }
+            Ok(())
+        }
+
+        // Hand a fractionalized collectible over to the winner of its buyout, keeping the bid for the shareholders
+        fn do_settle_buyout(collectible_id: [u8; 16]) -> DispatchResult{
+            let buyout = Buyouts::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoBuyout)?;
+            ensure!(frame_system::Pallet::<T>::block_number() > buyout.end, Error::<T, I>::BuyoutNotOver);
+            Buyouts::<T, I>::remove(collectible_id);
+            EndingBuyouts::<T, I>::remove(buyout.end.saturating_add(One::one()), collectible_id);
+            let (asset, total_shares) = Fractionalized::<T, I>::take(&collectible_id).ok_or(Error::<T, I>::NotFractionalized)?;
+            Self::unlock_for(collectible_id, LockReason::Fractionalized)?;
+            if Self::owner_of(collectible_id).as_ref() != Some(&buyout.bidder){
+                Self::do_transfer(collectible_id, buyout.bidder.clone())?;
+            }
+            BuyoutProceeds::<T, I>::insert(collectible_id, (asset, total_shares, buyout.bid));
+            Self::deposit_event(Event::BuyoutSettled { collectible: collectible_id, buyer: buyout.bidder, price: buyout.bid });
+            Ok(())
+        }
+
+        // Destroy the asset of a collectible's shares once they're all burned, as it's never minted again. Approvals
+        // shareholders left behind keep it from being finished here, anyone can finish it through the assets pallet then
+        fn destroy_shares(asset: AssetIdOf<T, I>) -> DispatchResult{
+            T::Assets::start_destroy(asset, None)?;
+            let _ = T::Assets::finish_destroy(asset);
+            Ok(())
+        }
+
+        // Whether the buyout of the collectible has ended and can be settled
+        fn buyout_over(collectible_id: [u8; 16]) -> bool{
+            Buyouts::<T, I>::get(collectible_id)
+                .map_or(false, |buyout| frame_system::Pallet::<T>::block_number() > buyout.end)
+        }
+
+        fn ensure_faucet_claimable(who: &T::AccountId) -> DispatchResult{
+            ensure!(T::FaucetEnabled::get(), Error::<T, I>::FaucetDisabled);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                LastFaucetClaim::<T, I>::get(who).map_or(true, |last| now >= last.saturating_add(T::FaucetCooldown::get())),
+                Error::<T, I>::FaucetCooldown
+            );
+            let (block, claims) = FaucetClaims::<T, I>::get();
+            ensure!(block != now || claims < T::MaxFaucetClaimsPerBlock::get(), Error::<T, I>::FaucetExhausted);
+            Ok(())
+        }
+
+        /// Whether `who` may dispatch `call` without paying fees: it claims something sent to them, which they may have
+        /// no funds to pay for yet. Backs the `feeless::SkipFeelessClaims` transaction extension
+        pub fn is_feeless(who: &T::AccountId, call: &Call<T, I>) -> bool{
+            match call{
+                Call::claim_airdrop { collectible_id } => {
+                    Airdrops::<T, I>::get(collectible_id).map_or(false, |(_, recipient)| recipient == *who)
+                },
+                Call::claim_gift { collectible_id } => {
+                    Gifts::<T, I>::get(collectible_id).map_or(false, |(_, recipient, _)| recipient == *who)
+                },
+                _ => false,
+            }
+        }
+
+        // Whether the collectible is listed past the expiry its owner set
+        fn listing_expired(collectible_id: [u8; 16]) -> bool{
+            ListingExpiries::<T, I>::get(collectible_id)
+                .map_or(false, |until| frame_system::Pallet::<T>::block_number() > until)
+        }
+
+        // Expire a new listing after `MaxListingDuration`
+        fn cap_listing_duration(collectible_id: [u8; 16]){
+            let until = frame_system::Pallet::<T>::block_number().saturating_add(T::MaxListingDuration::get());
+            Self::expire_listing_after(collectible_id, until);
+        }
+
+        // Expire a listing after block `until`, indexing it by the block it's expired in
+        fn expire_listing_after(collectible_id: [u8; 16], until: BlockNumberFor<T>){
+            Self::clear_listing_expiry(collectible_id);
+            ListingExpiries::<T, I>::insert(collectible_id, until);
+            ExpiringListings::<T, I>::insert(until.saturating_add(One::one()), collectible_id, ());
+        }
+
+        fn clear_listing_expiry(collectible_id: [u8; 16]){
+            if let Some(until) = ListingExpiries::<T, I>::take(collectible_id){
+                ExpiringListings::<T, I>::remove(until.saturating_add(One::one()), collectible_id);
+            }
+        }
+
+        // The key of the offchain worker's cursor, in the local storage of the node
+        fn due_cursor_key() -> Vec<u8>{
+            (b"collectibles::due_cursor", <Pallet<T, I> as PalletInfoAccess>::index() as u32).encode()
+        }
+
+        // Whether `approvers` hold more than the threshold of the shares of a co-owned collectible, or all of them
+        fn approved_by(co_ownership: &CoOwnership<T, I>, approvers: &[T::AccountId]) -> bool{
+            let (approved, total) = co_ownership.owners.iter().fold((0u32, 0u32), |(approved, total), (owner, shares)| {
+                let approved = if approvers.contains(owner){ approved.saturating_add(*shares) } else { approved };
+                (approved, total.saturating_add(*shares))
+            });
+            approved == total || approved > co_ownership.threshold.mul_floor(total)
+        }
+
+        // Record the approval of `co_owner` to list a co-owned collectible at `price`, returning whether the approvals
+        // suffice for the listing to take effect
+        fn approve_listing(
+            collectible_id: [u8; 16],
+            co_ownership: &CoOwnership<T, I>,
+            co_owner: T::AccountId,
+            price: BalanceOf<T, I>
+        ) -> Result<bool, DispatchError>{
+            ensure!(co_ownership.owners.iter().any(|(owner, _)| *owner == co_owner), Error::<T, I>::NotCoOwner);
+            let now = frame_system::Pallet::<T>::block_number();
+            let (mut approvers, expiry) = match ListingApprovals::<T, I>::get(&collectible_id){
+                Some((proposed, approvers, expiry)) if proposed == price && now <= expiry => (approvers, expiry),
+                _ => (BoundedVec::default(), now.saturating_add(T::ApprovalPeriod::get())),
+            };
+            ensure!(!approvers.contains(&co_owner), Error::<T, I>::AlreadyApproved);
+            // Co-owners are distinct, so there's room for each of them
+            approvers.try_push(co_owner.clone()).map_err(|_| Error::<T, I>::InvalidCoOwners)?;
+            Self::deposit_event(Event::ListingApproved { collectible: collectible_id, co_owner, price });
+
+            let approved = Self::approved_by(co_ownership, &approvers);
+            if approved{
+                ListingApprovals::<T, I>::remove(collectible_id);
+            }
+            else{
+                ListingApprovals::<T, I>::insert(collectible_id, (price, approvers, expiry));
+            }
+            Ok(approved)
+        }
+
+        // Record the approval of `co_owner` to delist a co-owned collectible, returning whether the approvals suffice for
+        // the delisting to take effect
+        fn approve_delisting(
+            collectible_id: [u8; 16],
+            co_ownership: &CoOwnership<T, I>,
+            co_owner: T::AccountId
+        ) -> Result<bool, DispatchError>{
+            ensure!(co_ownership.owners.iter().any(|(owner, _)| *owner == co_owner), Error::<T, I>::NotCoOwner);
+            let now = frame_system::Pallet::<T>::block_number();
+            let (mut approvers, expiry) = match DelistingApprovals::<T, I>::get(&collectible_id){
+                Some((approvers, expiry)) if now <= expiry => (approvers, expiry),
+                _ => (BoundedVec::default(), now.saturating_add(T::ApprovalPeriod::get())),
+            };
+            ensure!(!approvers.contains(&co_owner), Error::<T, I>::AlreadyApproved);
+            // Co-owners are distinct, so there's room for each of them
+            approvers.try_push(co_owner.clone()).map_err(|_| Error::<T, I>::InvalidCoOwners)?;
+            Self::deposit_event(Event::DelistingApproved { collectible: collectible_id, co_owner });
+
+            let approved = Self::approved_by(co_ownership, &approvers);
+            if approved{
+                DelistingApprovals::<T, I>::remove(collectible_id);
+            }
+            else{
+                DelistingApprovals::<T, I>::insert(collectible_id, (approvers, expiry));
+            }
+            Ok(approved)
+        }
+
+        // The owner and the manager of a collectible can handle its listing
+        fn ensure_manages(collectible: &Collectible<T, I>, who: &T::AccountId) -> DispatchResult{
+            ensure!(
+                collectible.owner == *who || Managers::<T, I>::get(&collectible.unique_id).as_ref() == Some(who),
+                Error::<T, I>::NotOwnerOrManager
+            );
+            Ok(())
+        }
+
+        // Keep collectibles under their creator's relist cooldown from being listed too soon after changing hands
+        fn ensure_relist_allowed(collectible: &Collectible<T, I>) -> DispatchResult{
+            let (Some(cooldown), Some(acquired)) = (
+                RelistCooldowns::<T, I>::get(&collectible.creator),
+                Acquisitions::<T, I>::get(&collectible.unique_id)
+            ) else {
+                return Ok(());
+            };
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= acquired.saturating_add(cooldown),
+                Error::<T, I>::RelistCooldown
+            );
+            Ok(())
+        }
+
+        // Approve `delegate` to transfer a collectible of `owner`, or revoke the approval
+        fn do_approve(owner: &T::AccountId, collectible_id: [u8; 16], delegate: Option<T::AccountId>) -> DispatchResult{
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == *owner, Error::<T, I>::NotOwner);
+            Approvals::<T, I>::set(collectible_id, delegate.clone());
+            Self::deposit_event(Event::ApprovalSet { collectible: collectible_id, owner: owner.clone(), delegate });
+            Ok(())
+        }
+
+        // Transfer a collectible of `from` on behalf of them. Taxes and royalties apply as on plain transfers, paid by `from`
+        fn do_transfer_from(
+            caller: &T::AccountId,
+            from: &T::AccountId,
+            to: T::AccountId,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == *from, Error::<T, I>::NotOwner);
+            ensure!(
+                caller == from || Approvals::<T, I>::get(&collectible_id).as_ref() == Some(caller),
+                Error::<T, I>::NotApproved
+            );
+            with_storage_layer(|| {
+                Self::charge_transfer_tax(&collectible, from)?;
+                Self::enforce_royalty(collectible_id, from, &to)?;
+                Self::do_transfer(collectible_id, to)
+            })
+        }
+
+        // Like `do_transfer`, the balance transfer and the storage updates are rolled back together if anything fails.
+        // The price is paid in whatever the collectible is listed in
+        pub fn do_buy(
+            collectible_id: [u8; 16],
+            buyer: T::AccountId,
+            price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            Self::do_buy_in(collectible_id, buyer, ListingAssets::<T, I>::get(&collectible_id), price, Preservation::Preserve)
+        }
+
+        // Buy the collectible paying `price` in `asset`, or in `Currency` if `None`. `preservation` applies to the
+        // seller's payment, the last one, so the buyer can spend their whole balance on it
+        fn do_buy_in(
+            collectible_id: [u8; 16],
+            buyer: T::AccountId,
+            asset: Option<AssetIdOf<T, I>>,
+            price: BalanceOf<T, I>,
+            preservation: Preservation
+        ) -> DispatchResult{
+            with_storage_layer(|| {
+                ensure!(TransfersEnabled::<T, I>::get(), Error::<T, I>::TransfersDisabled);
+                ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+                Self::ensure_can_buy(collectible_id, &buyer)?;
+                let (collectible, seller) = Self::pre_transfer(collectible_id, &buyer)?;
+                // If updating the storage fails after the balance transfer, the storage layer rolls the payment back too
+                let (charity, burned, royalty, proceeds) = Self::pay_sale(
+                    collectible_id,
+                    asset,
+                    &buyer,
+                    &seller,
+                    CoOwned::<T, I>::get(&collectible_id),
+                    price,
+                    preservation
+                )?;
+                // Update storage
+                Self::post_transfer(&collectible, &seller, &buyer)?;
+                if asset.is_none(){
+                    LastSalePrice::<T, I>::insert(collectible_id, price);
+                }
+                Self::record_trade(collectible_id, &seller, &buyer, asset, price);
+                match asset{
+                    Some(asset) => Self::deposit_event(
+                        Event::SoldInAsset{
+                            seller,
+                            buyer,
+                            collectible: collectible_id,
+                            color: collectible.color,
+                            asset,
+                            price,
+                            charity,
+                            burned,
+                            royalty,
+                            proceeds
+                        }
+                    ),
+                    None => Self::deposit_event(
+                        Event::Sold{
+                            seller,
+                            buyer,
+                            collectible: collectible_id,
+                            color: collectible.color,
+                            price,
+                            charity,
+                            burned,
+                            royalty,
+                            proceeds
+                        }
+                    )
+                }
+                Ok(())
+            })
+        }
+
+        // Add a sale to the trade history of both parties, dropping their oldest trade if it's full
+        fn record_trade(
+            collectible_id: [u8; 16],
+            seller: &T::AccountId,
+            buyer: &T::AccountId,
+            asset: Option<AssetIdOf<T, I>>,
+            price: BalanceOf<T, I>
+        ){
+            let at = frame_system::Pallet::<T>::block_number();
+            for (account, counterparty, bought) in [(seller, buyer, false), (buyer, seller, true)]{
+                TradeHistory::<T, I>::mutate(account, |history| {
+                    let trade = Trade::<T, I> { collectible: collectible_id, bought, counterparty: counterparty.clone(), asset, price, at };
+                    if !history.is_empty() && history.len() as u32 >= T::MaxTradeHistory::get(){
+                        history.remove(0);
+                    }
+                    // Fails only if `MaxTradeHistory` is zero, then there's no history to keep
+                    let _ = history.try_push(trade);
+                });
+            }
+        }
+
+        // The royalty paid on sales of a collectible: its own, or else its creator's default one
+        fn royalty_of(collectible_id: [u8; 16]) -> Option<Royalty<T::AccountId>>{
+            Royalties::<T, I>::get(&collectible_id).or_else(|| {
+                CollectibleMap::<T, I>::get(&collectible_id).and_then(|collectible| DefaultRoyalties::<T, I>::get(&collectible.creator))
+            })
+        }
+
+        // Creators can't set royalties above `MaxRoyalty` or outside the range governance allows
+        fn ensure_royalty_allowed(royalty: &Option<Royalty<T::AccountId>>) -> DispatchResult{
+            let Some(Royalty { rate, .. }) = royalty else {
+                return Ok(());
+            };
+            ensure!(*rate <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+            if let Some((lowest, highest)) = RoyaltyRange::<T, I>::get(){
+                ensure!(lowest <= *rate && *rate <= highest, Error::<T, I>::RoyaltyOutOfRange);
+            }
+            Ok(())
+        }
+
+        // Tell apart collectibles that don't exist from those `who` doesn't own
+        fn ensure_owner(collectible_id: [u8; 16], who: &T::AccountId) -> DispatchResult{
+            let owner = Self::owner_of(collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(owner == *who, Error::<T, I>::NotOwner);
+            Ok(())
+        }
+
+        // Whether `buyer` may buy the collectible now: they have the identity and meet the condition the listing may
+        // require, the listing hasn't expired and the owner didn't pause trading
+        fn ensure_can_buy(collectible_id: [u8; 16], buyer: &T::AccountId) -> DispatchResult{
+            ensure!(
+                !BuyersNeedIdentity::<T, I>::get() || T::Identity::has_judged_identity(buyer),
+                Error::<T, I>::NoJudgedIdentity
+            );
+            ensure!(
+                ListingConditions::<T, I>::get(&collectible_id)
+                    .map_or(true, |condition| T::BuyerQualification::qualifies(buyer, &condition)),
+                Error::<T, I>::BuyerNotQualified
+            );
+            ensure!(!Self::listing_expired(collectible_id), Error::<T, I>::ListingExpired);
+            ensure!(!TradingPaused::<T, I>::contains_key(&collectible_id), Error::<T, I>::TradingPaused);
+            Ok(())
+        }
+
+        // Split a sale price paid by `payer` between the charity, the burn, the royalty beneficiary and the seller, or
+        // its co-owners. Returns the charity cut, the burned part, the royalty and the seller's proceeds
+        fn pay_sale(
+            collectible_id: [u8; 16],
+            asset: Option<AssetIdOf<T, I>>,
+            payer: &T::AccountId,
+            seller: &T::AccountId,
+            co_ownership: Option<CoOwnership<T, I>>,
+            price: BalanceOf<T, I>,
+            preservation: Preservation
+        ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>, BalanceOf<T, I>, BalanceOf<T, I>), DispatchError>{
+            let mut charity = Zero::zero();
+            if let Some((beneficiary, cut)) = CharityCut::<T, I>::get(){
+                charity = cut.mul_floor(price);
+                Self::pay(asset, payer, &beneficiary, charity, Preservation::Preserve)?;
+            }
+            let burned = Self::burn_from_sale(asset, payer, SaleBurn::<T, I>::get().mul_floor(price))?;
+            let mut proceeds = price.saturating_sub(charity).saturating_sub(burned);
+            let mut royalty = Zero::zero();
+            if let Some(Royalty { beneficiary, rate }) = Self::royalty_of(collectible_id){
+                // Sellers that are their own beneficiary keep the royalty in the proceeds
+                if beneficiary != *seller{
+                    royalty = rate.mul_floor(price).min(proceeds);
+                    Self::pay(asset, payer, &beneficiary, royalty, Preservation::Preserve)?;
+                    proceeds = proceeds.saturating_sub(royalty);
+                }
+            }
+            match co_ownership{
+                Some(co_ownership) => Self::pay_co_owners(asset, payer, &co_ownership, proceeds, preservation)?,
+                None => Self::pay(asset, payer, seller, proceeds, preservation)?,
+            }
+            Ok((charity, burned, royalty, proceeds))
+        }
+
+        // Pay an escrowed sale out of its escrow account and hand the collectible over to the buyer for good
+        fn release_escrow_to_seller(collectible_id: [u8; 16], escrow: Escrow<T, I>) -> DispatchResult{
+            Escrows::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::Escrow)?;
+            let (charity, burned, royalty, proceeds) = Self::pay_sale(
+                collectible_id,
+                None,
+                &Self::escrow_account(collectible_id),
+                &escrow.seller,
+                escrow.co_ownership,
+                escrow.price,
+                Preservation::Expendable
+            )?;
+            LastSalePrice::<T, I>::insert(collectible_id, escrow.price);
+            Self::record_trade(collectible_id, &escrow.seller, &escrow.buyer, None, escrow.price);
+            let color = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?.color;
+            Self::deposit_event(Event::Sold {
+                seller: escrow.seller,
+                buyer: escrow.buyer,
+                collectible: collectible_id,
+                color,
+                price: escrow.price,
+                charity,
+                burned,
+                royalty,
+                proceeds
+            });
+            Self::deposit_event(Event::EscrowReleased { collectible: collectible_id });
+            Ok(())
+        }
+
+        // Keep plain transfers from dodging the royalty of a collectible, according to the enforcement mode
+        fn enforce_royalty(collectible_id: [u8; 16], from: &T::AccountId, to: &T::AccountId) -> DispatchResult{
+            Self::enforce_royalty_paid_by(collectible_id, from, to, from)
+        }
+
+        // Like `enforce_royalty`, with the minimum royalty paid by `payer` instead of the sender
+        fn enforce_royalty_paid_by(
+            collectible_id: [u8; 16],
+            from: &T::AccountId,
+            to: &T::AccountId,
+            payer: &T::AccountId
+        ) -> DispatchResult{
+            let Some(mode) = RoyaltyEnforcementMode::<T, I>::get() else {
+                return Ok(());
+            };
+            let Some(Royalty { beneficiary, .. }) = Self::royalty_of(collectible_id) else {
+                return Ok(());
+            };
+            if RoyaltyExempt::<T, I>::contains_key(to) || beneficiary == *from{
+                return Ok(());
+            }
+            match mode{
+                RoyaltyEnforcement::Block => Err(Error::<T, I>::RoyaltyEnforced.into()),
+                RoyaltyEnforcement::MinimumRoyalty(amount) => {
+                    T::Currency::transfer(payer, &beneficiary, amount, Preservation::Preserve)?;
+                    Self::deposit_event(Event::TransferRoyaltyPaid {
+                        collectible: collectible_id,
+                        payer: payer.clone(),
+                        beneficiary,
+                        amount
+                    });
+                    Ok(())
+                }
+            }
+        }
+
+        // Charge the transfer tax, if any, to the sender of a plain transfer
+        fn charge_transfer_tax(collectible: &Collectible<T, I>, payer: &T::AccountId) -> DispatchResult{
+            let Some((fee, recipient)) = TransferTax::<T, I>::get() else {
+                return Ok(());
+            };
+            let amount = match fee{
+                TransferFee::Flat(amount) => amount,
+                TransferFee::OfLastSale(part) => LastSalePrice::<T, I>::get(&collectible.unique_id)
+                    .map_or_else(Zero::zero, |price| part.mul_floor(price)),
+            };
+            let recipient = match recipient{
+                FeeRecipient::Creator => collectible.creator.clone(),
+                FeeRecipient::Treasury => T::TreasuryAccount::get(),
+            };
+            if amount.is_zero() || recipient == *payer{
+                return Ok(());
+            }
+            T::Currency::transfer(payer, &recipient, amount, Preservation::Preserve)?;
+            Self::deposit_event(Event::TransferTaxPaid {
+                collectible: collectible.unique_id,
+                payer: payer.clone(),
+                recipient,
+                amount
+            });
+            Ok(())
+        }
+
+        // Buy a collectible listed in the native currency, or in a reference unit
+        fn buy_listed(
+            collectible_id: [u8; 16],
+            buyer: T::AccountId,
+            offered_price: BalanceOf<T, I>,
+            preservation: Preservation
+        ) -> DispatchResult{
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            ensure!(!ListingAssets::<T, I>::contains_key(&collectible_id), Error::<T, I>::WrongPaymentAsset);
+            let price = Self::quote(&collectible).ok_or(Error::<T, I>::OracleUnavailable)?;
+            ensure!(offered_price >= price, Error::<T, I>::OfferedPriceTooLow);
+            Self::ensure_not_touched(collectible_id)?;
+            Self::do_buy_in(collectible_id, buyer, None, offered_price, preservation)
+        }
+
+        // Move part of a purchase price, in `asset` or in the native currency if `None`
+        fn pay(
+            asset: Option<AssetIdOf<T, I>>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T, I>,
+            preservation: Preservation
+        ) -> DispatchResult{
+            if amount.is_zero(){
+                return Ok(());
+            }
+            match asset{
+                Some(asset) => T::Assets::transfer(asset, from, to, amount, preservation).map(|_| ()),
+                None => T::Currency::transfer(from, to, amount, preservation).map(|_| ()),
+            }
+        }
+
+        // Burn part of a purchase price from the buyer, adding it to the burned totals
+        fn burn_from_sale(
+            asset: Option<AssetIdOf<T, I>>,
+            buyer: &T::AccountId,
+            amount: BalanceOf<T, I>
+        ) -> Result<BalanceOf<T, I>, DispatchError>{
+            if amount.is_zero(){
+                return Ok(amount);
+            }
+            match asset{
+                Some(asset) => {
+                    let burned = T::Assets::burn_from(asset, buyer, amount, Precision::Exact, Fortitude::Polite)?;
+                    AssetsBurnedFromSales::<T, I>::mutate(asset, |total| *total = total.saturating_add(burned));
+                    Ok(burned)
+                },
+                None => {
+                    let burned = T::Currency::burn_from(buyer, amount, Precision::Exact, Fortitude::Polite)?;
+                    BurnedFromSales::<T, I>::mutate(|total| *total = total.saturating_add(burned));
+                    Ok(burned)
+                }
+            }
+        }
+
+        /// This function encapsulates all the logic needed before a transfer/purchase
+        fn pre_transfer(
+            collectible_id: [u8; 16],
+            to: &T::AccountId
+        ) -> Result<(Collectible<T, I>, T::AccountId), Error<T, I>>{
+            // The callable functions already check that the collectible exists, but other pallets may not
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            let from = collectible.owner;
+            let enumerate = T::EnumerateOwnership::get();
+            ensure!(
+                !enumerate || OwnerOfCollectibles::<T, I>::contains_key(&from, &collectible_id),
+                Error::<T, I>::CorruptedOwnershipIndex
+            );
+            ensure!(!LockedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleLocked);
+            // Ensure the collectible isn't sent to its owner
+            ensure!(from != *to, Error::<T, I>::TransferToSelf);
+            ensure!(
+                !enumerate || MaximumOwned::<T, I>::get().map_or(true, |maximum_owned| OwnedCount::<T, I>::get(to) < maximum_owned),
+                Error::<T, I>::MaximumCollectiblesOwned
+            );
+
+            collectible.owner = to.clone();
+            collectible.price = None; // After transfer, the token isn't in sale, its new owner must set the desired price if wishing to sell it
+
+            Ok((collectible, from))
+        }
+
+        // This function updates storage after every transfer/purchase, once the `TransferFilter` allows it
+        fn post_transfer(
+            collectible: &Collectible<T, I>,
+            from: &T::AccountId,
+            to: &T::AccountId
+        ) -> DispatchResult{
+            T::TransferFilter::check(from, to, &collectible.unique_id)?;
+            ensure!(
+                AllowedHolders::<T, I>::get(&collectible.unique_id).map_or(true, |allowed| allowed.contains(to)),
+                Error::<T, I>::HolderNotAllowed
+            );
+            Self::add_to_owner(to, collectible.unique_id)?;
+            Self::hand_over(collectible, from, to)
+        }
+
+        // Move a collectible, already indexed under its new owner, out of its previous owner's hands
+        fn hand_over(
+            collectible: &Collectible<T, I>,
+            from: &T::AccountId,
+            to: &T::AccountId
+        ) -> DispatchResult{
+            // Revenue deposited while `from` held the collectible is still theirs
+            Self::settle_revenue(collectible.unique_id, from);
+            Self::remove_from_owner(from, collectible.unique_id);
+            Self::repatriate_deposit(collectible.unique_id, from, to)?;
+            Self::clear_price_unit(collectible.unique_id);
+            Self::clear_rental_offer(collectible.unique_id);
+            Approvals::<T, I>::remove(collectible.unique_id);
+            Managers::<T, I>::remove(collectible.unique_id);
+            // Co-ownership ends when the collectible leaves its co-ownership account
+            let co_ownership = CoOwned::<T, I>::take(collectible.unique_id);
+            DisposalApprovals::<T, I>::remove(collectible.unique_id);
+            ListingApprovals::<T, I>::remove(collectible.unique_id);
+            DelistingApprovals::<T, I>::remove(collectible.unique_id);
+            TradingPaused::<T, I>::remove(collectible.unique_id);
+            if RelistCooldowns::<T, I>::contains_key(&collectible.creator){
+                Acquisitions::<T, I>::insert(collectible.unique_id, frame_system::Pallet::<T>::block_number());
+            }
+            else{
+                Acquisitions::<T, I>::remove(collectible.unique_id);
+            }
+            if T::KeepNamesOnTransfer::get(){
+                Self::repatriate_name_deposit(collectible.unique_id, from, to)?;
+            }
+            else{
+                Self::remove_name(collectible.unique_id, from)?;
+            }
+            Self::remove_note(collectible.unique_id, from)?;
+            // The deposits given back to the co-ownership account, along with what funded it, go to the co-owners
+            if let Some(co_ownership) = co_ownership{
+                let left = T::Currency::reducible_balance(from, Preservation::Expendable, Fortitude::Polite);
+                if !left.is_zero(){
+                    Self::pay_co_owners(None, from, &co_ownership, left, Preservation::Expendable)?;
+                }
+            }
+            CollectibleMap::<T, I>::insert(collectible.unique_id, collectible);
+            Self::touch(collectible.unique_id);
+            Ok(())
+        }
+
+        // Hand the collateral of a defaulted loan over to its lender. The borrower agreed to it when taking the loan, so
+        // unlike transfers it isn't stopped by disabled transfers, stolen flags, the `TransferFilter`, allowed holders
+        // or `MaximumOwned`
+        fn seize_collateral(collectible_id: [u8; 16], lender: &T::AccountId) -> DispatchResult{
+            with_storage_layer(|| {
+                let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+                ensure!(!LockedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleLocked);
+                let from = collectible.owner;
+                collectible.owner = lender.clone();
+                collectible.price = None;
+                Self::index_owner(lender, collectible_id)?;
+                Self::hand_over(&collectible, &from, lender)?;
+                Self::deposit_event(Event::TransferSucceeded {
+                    from,
+                    to: lender.clone(),
+                    collectible: collectible_id,
+                    color: collectible.color
+                });
+                Ok(())
+            })
+        }
+
+        // Give the deposit of a collectible back to its previous owner and hold a new one from its new owner, who
+        // benefits from its storage from now on
+        fn repatriate_deposit(collectible_id: [u8; 16], from: &T::AccountId, to: &T::AccountId) -> DispatchResult{
+            if let Some(held) = ItemDeposits::<T, I>::get(&collectible_id){
+                T::Currency::release(&HoldReason::ItemDeposit.into(), from, held, Precision::BestEffort)?;
+                let deposit = ItemDeposit::<T, I>::get();
+                T::Currency::hold(&HoldReason::ItemDeposit.into(), to, deposit)?;
+                ItemDeposits::<T, I>::insert(collectible_id, deposit);
+            }
+            Ok(())
+        }
+
+        // Give the deposit of a collectible's name back to its previous owner and hold a new one from its new owner, who
+        // keeps the name
+        fn repatriate_name_deposit(collectible_id: [u8; 16], from: &T::AccountId, to: &T::AccountId) -> DispatchResult{
+            if let Some((name, held)) = Names::<T, I>::get(&collectible_id){
+                T::Currency::release(&HoldReason::NameDeposit.into(), from, held, Precision::BestEffort)?;
+                let deposit = NameDeposit::<T, I>::get();
+                T::Currency::hold(&HoldReason::NameDeposit.into(), to, deposit)?;
+                Names::<T, I>::insert(collectible_id, (name, deposit));
+            }
+            Ok(())
+        }
+
+        // The deposits a collectible's new owner holds for it
+        fn deposits_of(collectible_id: [u8; 16]) -> BalanceOf<T, I>{
+            let mut deposits = Zero::zero();
+            if ItemDeposits::<T, I>::contains_key(&collectible_id){
+                deposits = ItemDeposit::<T, I>::get();
+            }
+            if T::KeepNamesOnTransfer::get() && Names::<T, I>::contains_key(&collectible_id){
+                deposits = deposits.saturating_add(NameDeposit::<T, I>::get());
+            }
+            deposits
+        }
+
+        // Drop the name of a collectible, if any, releasing its deposit to `owner`
+        fn remove_name(collectible_id: [u8; 16], owner: &T::AccountId) -> DispatchResult{
+            let Some((name, deposit)) = Names::<T, I>::take(&collectible_id) else {
+                return Ok(());
+            };
+            NamedCollectibles::<T, I>::remove(&name);
+            T::Currency::release(&HoldReason::NameDeposit.into(), owner, deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::NameCleared { collectible: collectible_id });
+            Ok(())
+        }
+
+        // Drop the note `owner` keeps on a collectible, if any, releasing its deposit
+        fn remove_note(collectible_id: [u8; 16], owner: &T::AccountId) -> DispatchResult{
+            let Some((_, deposit)) = Notes::<T, I>::take(owner, collectible_id) else {
+                return Ok(());
+            };
+            T::Currency::release(&HoldReason::NoteDeposit.into(), owner, deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::NoteCleared { collectible: collectible_id, owner: owner.clone() });
+            Ok(())
+        }
+
+        // Hash a pair of Merkle tree nodes, in ascending order so proofs don't need the position of the nodes
+        fn hash_pair(a: &T::Hash, b: &T::Hash) -> T::Hash{
+            if a <= b{ T::Hashing::hash_of(&(a, b)) } else { T::Hashing::hash_of(&(b, a)) }
+        }
+
+        // Forget which unit the price of the collectible was set in, its alternative quotes and when its listing expires
+        fn clear_price_unit(collectible_id: [u8; 16]){
+            Self::clear_listing_expiry(collectible_id);
+            ScheduledPrices::<T, I>::remove(collectible_id);
+            ListingConditions::<T, I>::remove(collectible_id);
+            DecliningPrices::<T, I>::remove(collectible_id);
+            ListingAssets::<T, I>::remove(collectible_id);
+            ReferencePriced::<T, I>::remove(collectible_id);
+            AssetQuotes::<T, I>::remove(collectible_id);
+        }
+
+        // The price the collectible is listed at, in its price unit, switched to its scheduled price once due or
+        // lowered by its decline since it was listed
+        fn listed_price(collectible: &Collectible<T, I>) -> Option<BalanceOf<T, I>>{
+            let price = collectible.price?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some((scheduled, at)) = ScheduledPrices::<T, I>::get(collectible.unique_id){
+                if now >= at{
+                    return Some(scheduled);
+                }
+            }
+            match DecliningPrices::<T, I>::get(collectible.unique_id){
+                Some((decrement, floor, since)) => {
+                    let blocks: u32 = now.saturating_sub(since).saturated_into();
+                    Some(price.saturating_sub(decrement.saturating_mul(blocks.into())).max(floor))
+                },
+                None => Some(price),
+            }
+        }
+
+        // The price to pay for the collectible, converting reference prices to the native currency
+        fn quote(collectible: &Collectible<T, I>) -> Option<BalanceOf<T, I>>{
+            let price = Self::listed_price(collectible)?;
+            if ReferencePriced::<T, I>::contains_key(collectible.unique_id){
+                T::PriceOracle::to_native(price)
+            }
+            else{
+                Some(price)
+            }
+        }
+
+        // Schedule the automatic closing of a rental at block `expiry`
+        fn queue_rental_expiry(collectible_id: [u8; 16], expiry: BlockNumberFor<T>){
+            // A stale entry of a rental closed early may already be there
+            if RentalExpiries::<T, I>::contains_key(expiry, collectible_id){
+                return;
+            }
+            RentalExpiries::<T, I>::insert(expiry, collectible_id, ());
+            QueuedRentalExpiries::<T, I>::mutate(|queued| *queued = queued.defensive_saturating_add(1));
+            NextRentalExpiry::<T, I>::mutate(|next| {
+                if next.map_or(true, |next| expiry < next){
+                    *next = Some(expiry);
+                }
+            });
+        }
+
+        // Execute the transfers scheduled at block `now`. A failing transfer leaves the collectible unlocked with its owner
+        pub(crate) fn execute_scheduled_transfers(now: BlockNumberFor<T>) -> Weight{
+            let queued = ScheduledTransferQueue::<T, I>::take(now);
+            let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+            for collectible_id in queued{
+                consumed.saturating_accrue(T::WeightInfo::execute_scheduled_transfer());
+                let Some((to, _)) = ScheduledTransfers::<T, I>::take(collectible_id) else {
+                    defensive!("Queued transfers are scheduled until executed or cancelled");
+                    continue;
+                };
+                let executed = Self::unlock_for(collectible_id, LockReason::ScheduledTransfer)
+                    .and_then(|_| Self::do_transfer(collectible_id, to));
+                if let Err(error) = executed{
+                    Self::deposit_event(Event::ScheduledTransferFailed { collectible: collectible_id, error });
+                }
+            }
+            consumed
+        }
+
+        // Give the gifts left unclaimed past their deadline back to their senders
+        pub(crate) fn revert_expired_gifts(now: BlockNumberFor<T>) -> Weight{
+            let queued = GiftExpiryQueue::<T, I>::take(now);
+            let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+            for collectible_id in queued{
+                consumed.saturating_accrue(T::WeightInfo::revert_gift());
+                if Gifts::<T, I>::take(collectible_id).is_none(){
+                    defensive!("Queued gifts are pending until claimed or reverted");
+                    continue;
+                }
+                if Self::unlock_for(collectible_id, LockReason::Gift).is_ok(){
+                    Self::deposit_event(Event::GiftReverted { collectible: collectible_id });
+                }
+            }
+            consumed
+        }
+
+        // Walk the rental expiry queue up to block `now` within `budget`. Whatever doesn't fit is left for the next
+        // block's idle time; until then, `user_of` already ignores rentals past their term
+        pub(crate) fn expire_rentals(now: BlockNumberFor<T>, budget: Weight) -> Weight{
+            let db_weight = T::DbWeight::get();
+            // Reading the queue state, and writing it back
+            let mut consumed = db_weight.reads_writes(2, 2);
+            if consumed.any_gt(budget){
+                return Weight::zero();
+            }
+            let Some(mut block) = NextRentalExpiry::<T, I>::get() else {
+                return db_weight.reads(1);
+            };
+            if block > now{
+                return db_weight.reads(1);
+            }
+            let first = block;
+            let mut queued = QueuedRentalExpiries::<T, I>::get();
+            let step = T::WeightInfo::rental_expiry_step();
+            let per_rental = T::WeightInfo::expire_rental();
+
+            while queued > 0 && block <= now && consumed.saturating_add(step).all_lte(budget){
+                consumed.saturating_accrue(step);
+                let mut processed = 0u32;
+                let mut drained = false;
+                // Renewals queue their next expiry in a later block, so the block's entries are taken one at a time
+                loop{
+                    let Some(collectible_id) = RentalExpiries::<T, I>::iter_key_prefix(block).next() else {
+                        drained = true;
+                        break;
+                    };
+                    if consumed.saturating_add(per_rental).any_gt(budget){
+                        break;
+                    }
+                    consumed.saturating_accrue(per_rental);
+                    RentalExpiries::<T, I>::remove(block, collectible_id);
+                    Self::expire_rental(collectible_id, block);
+                    processed += 1;
+                }
+                // Renewals count their next expiry as they go, so the counter is updated in place rather than overwritten
+                queued = QueuedRentalExpiries::<T, I>::mutate(|queued| {
+                    *queued = queued.defensive_saturating_sub(processed);
+                    *queued
+                });
+                if !drained{
+                    // Out of budget, the rest of the block's expiries stay queued
+                    break;
+                }
+                block = block.saturating_add(One::one());
+            }
+
+            if queued == 0{
+                NextRentalExpiry::<T, I>::kill();
+            }
+            else{
+                // A renewal may have queued an expiry before the block the scan stopped at
+                let next = NextRentalExpiry::<T, I>::get().filter(|next| *next < first).map_or(block, |next| next.min(block));
+                NextRentalExpiry::<T, I>::put(next);
+            }
+            consumed
+        }
+
+        // Close the rental of a collectible queued to expire at `block`. Rentals closed or renewed since they were
+        // queued are left alone
+        pub(crate) fn expire_rental(collectible_id: [u8; 16], block: BlockNumberFor<T>){
+            let Some((renter, until)) = Rentals::<T, I>::get(&collectible_id) else {
+                return;
+            };
+            if until >= block || Self::renew_rental(collectible_id, &renter, until){
+                return;
+            }
+            if Self::close_rental(collectible_id, renter).is_err(){
+                defensive!("A rented collectible isn't locked");
+            }
+        }
+
+        // Pay another period of a streaming rental from the renter's hold. Returns whether the rental was renewed
+        fn renew_rental(collectible_id: [u8; 16], renter: &T::AccountId, until: BlockNumberFor<T>) -> bool{
+            let Some(stream) = RentStreams::<T, I>::get(&collectible_id) else {
+                return false;
+            };
+            if stream.periods_left == 0 || stream.held < stream.price_per_period{
+                return false;
+            }
+            let until = until.saturating_add(T::RentalPeriod::get());
+            with_storage_layer(|| -> DispatchResult {
+                let owner = Self::owner_of(collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+                T::Currency::transfer_on_hold(
+                    &HoldReason::RentStream.into(),
+                    renter,
+                    &owner,
+                    stream.price_per_period,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite
+                )?;
+                Self::accrue_rent_credit(collectible_id, renter, stream.price_per_period);
+                Self::queue_rental_expiry(collectible_id, until.saturating_add(One::one()));
+                Rentals::<T, I>::insert(collectible_id, (renter.clone(), until));
+                RentStreams::<T, I>::insert(collectible_id, RentStream {
+                    held: stream.held.saturating_sub(stream.price_per_period),
+                    periods_left: stream.periods_left.saturating_sub(1),
+                    ..stream
+                });
+                Self::deposit_event(Event::RentalRenewed { collectible: collectible_id, renter: renter.clone(), until });
+                Ok(())
+            }).is_ok()
+        }
+
+        // Close a rental and unlock the collectible, releasing what the renter still holds if it was a streaming one
+        fn close_rental(collectible_id: [u8; 16], renter: T::AccountId) -> DispatchResult{
+            Rentals::<T, I>::remove(collectible_id);
+            if let Some(stream) = RentStreams::<T, I>::take(&collectible_id){
+                T::Currency::release(&HoldReason::RentStream.into(), &renter, stream.held, Precision::BestEffort)?;
+            }
+            Self::unlock_for(collectible_id, LockReason::Rental)?;
+            Self::deposit_event(Event::RentalEnded { collectible: collectible_id, renter });
+            Ok(())
+        }
+
+        // Under rent-to-own terms, part of the rent counts towards the purchase. Credit doesn't carry over between renters
+        fn accrue_rent_credit(collectible_id: [u8; 16], renter: &T::AccountId, rent: BalanceOf<T, I>){
+            if let Some((_, share)) = RentToOwnTerms::<T, I>::get(&collectible_id){
+                let credit = match RentCredit::<T, I>::get(&collectible_id){
+                    Some((previous_renter, credit)) if previous_renter == *renter => credit,
+                    _ => Zero::zero(),
+                };
+                RentCredit::<T, I>::insert(collectible_id, (renter.clone(), credit.saturating_add(share.mul_floor(rent))));
+            }
+        }
+
+        // The staking rewards a collectible has accrued since `since`
+        fn staking_rewards(collectible_id: [u8; 16], since: BlockNumberFor<T>) -> BalanceOf<T, I>{
+            let Some(collectible) = CollectibleMap::<T, I>::get(&collectible_id) else {
+                return Zero::zero();
+            };
+            let blocks: u128 = frame_system::Pallet::<T>::block_number().saturating_sub(since).saturated_into();
+            T::StakingRewardPerBlock::get()
+                .saturating_mul(blocks.saturated_into())
+                .saturating_mul(T::StakingMultiplier::convert(collectible.color).into())
+        }
+
+        // Pay the rewards a staked collectible accrued and start accruing again from now
+        fn pay_staking_rewards(collectible_id: [u8; 16], staker: &T::AccountId, since: BlockNumberFor<T>) -> DispatchResult{
+            let amount = Self::staking_rewards(collectible_id, since);
+            if !amount.is_zero(){
+                T::Currency::transfer(&T::StakingRewardSource::get(), staker, amount, Preservation::Preserve)?;
+            }
+            Stakes::<T, I>::insert(collectible_id, (staker.clone(), frame_system::Pallet::<T>::block_number()));
+            Self::deposit_event(Event::StakingRewardsPaid { collectible: collectible_id, staker: staker.clone(), amount });
+            Ok(())
+        }
+
+        // Credit `holder` with the revenue a collectible received since its checkpoint
+        fn settle_revenue(collectible_id: [u8; 16], holder: &T::AccountId){
+            let revenue_per_item = RevenuePerItem::<T, I>::get();
+            let pending = revenue_per_item.saturating_sub(RevenueCheckpoints::<T, I>::get(&collectible_id));
+            if !pending.is_zero(){
+                RevenueOwed::<T, I>::mutate(holder, |owed| *owed = owed.saturating_add(pending));
+                RevenueCheckpoints::<T, I>::insert(collectible_id, revenue_per_item);
+            }
+        }
+
+        // Withdraw the rental offer of a collectible, along with its rent-to-own terms and the credit accrued under them
+        fn clear_rental_offer(collectible_id: [u8; 16]){
+            RentalOffers::<T, I>::remove(collectible_id);
+            RentToOwnTerms::<T, I>::remove(collectible_id);
+            RentCredit::<T, I>::remove(collectible_id);
+        }
+
+        // Record that the collectible changed in this block
+        fn touch(collectible_id: [u8; 16]){
+            LastTouched::<T, I>::insert(collectible_id, frame_system::Pallet::<T>::block_number());
+        }
+
+        fn ensure_not_touched(collectible_id: [u8; 16]) -> DispatchResult{
+            ensure!(
+                LastTouched::<T, I>::get(collectible_id) != Some(frame_system::Pallet::<T>::block_number()),
+                Error::<T, I>::TouchedThisBlock
+            );
+            Ok(())
+        }
+    }
+
+    // Entry points for other pallets (staking, governance...) that need to freeze a collectible while they use it. Each
+    // pallet locks under its own `id` and can only lift its own lock
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        pub fn lock_collectible(collectible_id: [u8; 16], id: LockIdentifier) -> DispatchResult{
+            Self::lock_for(collectible_id, LockReason::External(id))
+        }
+
+        pub fn unlock_collectible(collectible_id: [u8; 16], id: LockIdentifier) -> DispatchResult{
+            Self::unlock_for(collectible_id, LockReason::External(id))
+        }
+
+        // A collectible holds a single lock at a time
+        pub(crate) fn lock_for(collectible_id: [u8; 16], reason: LockReason) -> DispatchResult{
+            ensure!(CollectibleMap::<T, I>::contains_key(&collectible_id), Error::<T, I>::NoCollectible);
+            ensure!(!LockedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::AlreadyLocked);
+            LockedCollectibles::<T, I>::insert(collectible_id, reason);
+            Self::deposit_event(Event::CollectibleLocked { collectible: collectible_id, reason });
+            Ok(())
+        }
+
+        // Only lift the lock if it was taken for `reason`
+        pub(crate) fn unlock_for(collectible_id: [u8; 16], reason: LockReason) -> DispatchResult{
+            ensure!(LockedCollectibles::<T, I>::get(&collectible_id) == Some(reason), Error::<T, I>::NotLocked);
+            LockedCollectibles::<T, I>::remove(collectible_id);
+            Self::deposit_event(Event::CollectibleUnlocked { collectible: collectible_id, reason });
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        /// Whether `owner` held the collectible when the snapshot was taken, given the hashes of the siblings of its leaf
+        /// from the bottom up, e.g. for airdrop or governance pallets checking claims against a snapshot
+        pub fn verify_holder(snapshot: u32, collectible_id: [u8; 16], owner: &T::AccountId, proof: &[T::Hash]) -> bool{
+            let Some(HolderSnapshot { root, .. }) = HolderSnapshots::<T, I>::get(snapshot) else {
+                return false;
+            };
+            let leaf = T::Hashing::hash_of(&(collectible_id, owner));
+            proof.iter().fold(leaf, |node, sibling| Self::hash_pair(&node, sibling)) == root
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        /// The account administering the assets created by the pallet
+        pub fn account_id() -> T::AccountId{
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// The pot keeping the backing of a reserve-backed collectible
+        pub fn backing_account(collectible_id: [u8; 16]) -> T::AccountId{
+            T::PalletId::get().into_sub_account_truncating(collectible_id)
+        }
+
+        /// The account holding a co-owned collectible on behalf of its co-owners
+        pub fn co_ownership_account(collectible_id: [u8; 16]) -> T::AccountId{
+            T::PalletId::get().into_sub_account_truncating((b"coown", collectible_id))
+        }
+
+        /// The bytes the owner of a collectible signs to pre-sign its transfer: the transfer prefixed by a domain tag, the
+        /// genesis hash and the pallet's index, so the signature is only valid for this kind of message in this
+        /// instance of the pallet on this chain
+        pub fn pre_signed_payload(transfer: &PreSignedTransfer<T::AccountId, BlockNumberFor<T>>) -> Vec<u8>{
+            (
+                b"collectibles/transfer",
+                frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+                <Pallet<T, I> as PalletInfoAccess>::index() as u32,
+                transfer
+            ).encode()
+        }
+
+        /// The account keeping the price of an escrowed sale until it's released or refunded
+        pub fn escrow_account(collectible_id: [u8; 16]) -> T::AccountId{
+            T::PalletId::get().into_sub_account_truncating((b"escrw", collectible_id))
+        }
+
+        /// The pot keeping the revenue distributed to holders until they claim it
+        pub fn revenue_account() -> T::AccountId{
+            T::PalletId::get().into_sub_account_truncating(b"revenue")
+        }
+    }
+
+    // The pallet holds a single collection, hence the unit collection id
+    impl<T: Config<I>, I: 'static> Locker<(), [u8; 16]> for Pallet<T, I>{
+        fn is_locked(_collection: (), collectible_id: [u8; 16]) -> bool{
+            LockedCollectibles::<T, I>::contains_key(collectible_id)
+        }
+    }
+
+    // The `nonfungibles` interface, which lets `xcm_builder::NonFungiblesAdapter` reserve-transfer collectibles to
+    // sibling chains: sending one moves it to the sibling's sovereign account through `Transfer`, and bringing it back
+    // withdraws it from there through `burn` and deposits it through `mint_into`. Burning only parks the collectible,
+    // so it comes back with its color, creator and history intact
+    impl<T: Config<I>, I: 'static> nonfungibles::Inspect<T::AccountId> for Pallet<T, I>{
+        type ItemId = [u8; 16];
+        type CollectionId = ();
+
+        fn owner(_collection: &(), collectible_id: &[u8; 16]) -> Option<T::AccountId>{
+            Self::owner_of(*collectible_id)
+        }
+
+        fn can_transfer(_collection: &(), collectible_id: &[u8; 16]) -> bool{
+            TransfersEnabled::<T, I>::get()
+                && !LockedCollectibles::<T, I>::contains_key(collectible_id)
+                && !StolenCollectibles::<T, I>::contains_key(collectible_id)
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> nonfungibles::Transfer<T::AccountId> for Pallet<T, I>{
+        fn transfer(_collection: &(), collectible_id: &[u8; 16], destination: &T::AccountId) -> DispatchResult{
+            ensure!(!StolenCollectibles::<T, I>::contains_key(collectible_id), Error::<T, I>::CollectibleStolen);
+            Self::do_transfer(*collectible_id, destination.clone())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> nonfungibles::Mutate<T::AccountId> for Pallet<T, I>{
+        fn mint_into(_collection: &(), collectible_id: &[u8; 16], who: &T::AccountId) -> DispatchResult{
+            ensure!(Parked::<T, I>::contains_key(collectible_id), Error::<T, I>::NotParked);
+            with_storage_layer(|| {
+                Parked::<T, I>::remove(collectible_id);
+                Self::unlock_for(*collectible_id, LockReason::Parked)?;
+                if Self::owner_of(*collectible_id).as_ref() != Some(who){
+                    Self::do_transfer(*collectible_id, who.clone())?;
+                }
+                Self::deposit_event(Event::CollectibleUnparked { collectible: *collectible_id, owner: who.clone() });
+                Ok(())
+            })
+        }
+
+        fn burn(_collection: &(), collectible_id: &[u8; 16], maybe_check_owner: Option<&T::AccountId>) -> DispatchResult{
+            let owner = Self::owner_of(*collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(maybe_check_owner.map_or(true, |check_owner| *check_owner == owner), Error::<T, I>::NotOwner);
+            Self::lock_for(*collectible_id, LockReason::Parked)?;
+            Parked::<T, I>::insert(collectible_id, ());
+            Self::deposit_event(Event::CollectibleParked { collectible: *collectible_id });
+            Ok(())
+        }
+    }
+
+    /// Lets other pallets of the runtime, e.g. achievement or quest pallets, mint collectibles into users' accounts as
+    /// rewards, within the quota governance granted them. Callers account for `WeightInfo::reward`
+    pub trait RewardCollectible<AccountId>{
+        /// Mint a collectible of `color` to `who` on behalf of the pallet `minter`, which is recorded as its creator.
+        /// Returns the collectible id
+        fn reward(minter: PalletId, who: &AccountId, color: Color) -> Result<[u8; 16], DispatchError>;
+    }
+
+    impl<T: Config<I>, I: 'static> RewardCollectible<T::AccountId> for Pallet<T, I>{
+        fn reward(minter: PalletId, who: &T::AccountId, color: Color) -> Result<[u8; 16], DispatchError>{
+            Self::ensure_not_paused()?;
+            // Callers may not roll back failed rewards, so the quota is only spent on minted collectibles
+            with_storage_layer(|| {
+                RewardQuotas::<T, I>::try_mutate(minter, |quota| -> DispatchResult{
+                    *quota = quota.checked_sub(1).ok_or(Error::<T, I>::RewardQuotaExhausted)?;
+                    Ok(())
+                })?;
+                let (unique_id, _) = Self::gen_unique_id();
+                let creator: T::AccountId = minter.into_account_truncating();
+                // Rewarded users may have no funds, so the collectible carries no deposit
+                Self::import(Collectible::<T, I> { unique_id, price: None, color, owner: who.clone(), creator })?;
+                Self::deposit_event(Event::CollectibleRewarded { collectible: unique_id, minter, to: who.clone() });
+                Ok(unique_id)
+            })
+        }
+    }
+
+    /// The ERC-721 surface of the collectibles, for an EVM precompile to expose them to Solidity contracts. Token ids are
+    /// the collectible ids read as big-endian integers, see `Pallet::token_id`, so they fit a `uint256`
+    pub trait Erc721<AccountId>{
+        /// `ownerOf`, `None` if the token doesn't exist
+        fn owner_of(token_id: u128) -> Option<AccountId>;
+        /// `getApproved`
+        fn get_approved(token_id: u128) -> Option<AccountId>;
+        /// `tokenURI`, `None` if the token doesn't exist
+        fn token_uri(token_id: u128) -> Option<Vec<u8>>;
+        /// `approve` called by `owner`, revoking the approval if `delegate` is `None`
+        fn approve(owner: &AccountId, delegate: Option<AccountId>, token_id: u128) -> DispatchResult;
+        /// `transferFrom` called by `caller`, either the owner or the account approved by them
+        fn transfer_from(caller: &AccountId, from: &AccountId, to: &AccountId, token_id: u128) -> DispatchResult;
+    }
+
+    impl<T: Config<I>, I: 'static> Erc721<T::AccountId> for Pallet<T, I>{
+        fn owner_of(token_id: u128) -> Option<T::AccountId>{
+            Self::owner_of(Self::collectible_id_of(token_id))
+        }
+
+        fn get_approved(token_id: u128) -> Option<T::AccountId>{
+            Approvals::<T, I>::get(Self::collectible_id_of(token_id))
+        }
+
+        fn token_uri(token_id: u128) -> Option<Vec<u8>>{
+            const HEX: &[u8; 16] = b"0123456789abcdef";
+            let collectible_id = Self::collectible_id_of(token_id);
+            CollectibleMap::<T, I>::contains_key(&collectible_id).then(|| {
+                let mut uri = T::BaseTokenUri::get();
+                for byte in collectible_id{
+                    uri.push(HEX[(byte >> 4) as usize]);
+                    uri.push(HEX[(byte & 0x0f) as usize]);
+                }
+                uri
+            })
+        }
+
+        fn approve(owner: &T::AccountId, delegate: Option<T::AccountId>, token_id: u128) -> DispatchResult{
+            Self::ensure_not_paused()?;
+            Self::do_approve(owner, Self::collectible_id_of(token_id), delegate)
+        }
+
+        fn transfer_from(caller: &T::AccountId, from: &T::AccountId, to: &T::AccountId, token_id: u128) -> DispatchResult{
+            Self::ensure_not_paused()?;
+            Self::do_transfer_from(caller, from, to.clone(), Self::collectible_id_of(token_id))
+        }
+    }
+
+    /// Membership provider for the accounts owning at least one collectible, e.g. to gate council seats or channels.
+    /// `Get<u32>` reports how many such accounts there are.
+    pub struct Holders<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> Contains<T::AccountId> for Holders<T, I>{
+        fn contains(who: &T::AccountId) -> bool{
+            OwnedCount::<T, I>::get(who) > 0
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Get<u32> for Holders<T, I>{
+        fn get() -> u32{
+            HoldersCount::<T, I>::get()
+        }
+    }
+
+    /// Source of the exchange rate between a reference unit, e.g. USD, and the native currency
+    pub trait PriceOracle<Balance>{
+        /// The native amount worth `amount` reference units, `None` if there's no fresh rate
+        fn to_native(amount: Balance) -> Option<Balance>;
+    }
+
+    impl<Balance> PriceOracle<Balance> for (){
+        fn to_native(_amount: Balance) -> Option<Balance>{
+            None
+        }
+    }
+
+    /// Source of identity judgements, e.g. `pallet_identity`
+    pub trait IdentityVerifier<AccountId>{
+        /// Whether a registrar has judged the identity of `who` as reasonable or known good
+        fn has_judged_identity(who: &AccountId) -> bool;
+    }
+
+    impl<AccountId> IdentityVerifier<AccountId> for (){
+        fn has_judged_identity(_who: &AccountId) -> bool{
+            false
+        }
+    }
+
+    /// Conditions buyers must meet to buy the listings that carry them, e.g. owning an item of some collection or being
+    /// on some allowlist
+    pub trait BuyerQualification<AccountId>{
+        /// A condition a seller can put on a listing
+        type Condition: Parameter + MaxEncodedLen;
+
+        /// Whether `buyer` meets `condition`
+        fn qualifies(buyer: &AccountId, condition: &Self::Condition) -> bool;
+    }
+
+    impl<AccountId> BuyerQualification<AccountId> for (){
+        type Condition = ();
+
+        fn qualifies(_buyer: &AccountId, _condition: &()) -> bool{
+            true
+        }
+    }
+
+    /// Compliance rules collectibles must pass to change hands
+    pub trait CheckTransfer<AccountId, ItemId>{
+        /// Whether `item` can move from `from` to `to`, failing with the reason it can't
+        fn check(from: &AccountId, to: &AccountId, item: &ItemId) -> DispatchResult;
+    }
+
+    impl<AccountId, ItemId> CheckTransfer<AccountId, ItemId> for (){
+        fn check(_from: &AccountId, _to: &AccountId, _item: &ItemId) -> DispatchResult{
+            Ok(())
+        }
+    }
+
+    /// Voting power that an account derives from its collectibles, so governance pallets can use them as the franchise
+    pub trait VotingPower<AccountId>{
+        fn voting_power(who: &AccountId) -> u64;
+        fn total_voting_power() -> u64;
+    }
+
+    /// One vote per collectible owned
+    pub struct HoldingsVotingPower<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> VotingPower<T::AccountId> for HoldingsVotingPower<T, I>{
+        fn voting_power(who: &T::AccountId) -> u64{
+            OwnedCount::<T, I>::get(who) as u64
+        }
+
+        fn total_voting_power() -> u64{
+            CollectibleMap::<T, I>::count() as u64
+        }
+    }
+
+    /// Each collectible counts as many votes as `W` assigns to its color, so rarer colors can weigh more.
+    /// The total is computed iterating every collectible, so it shouldn't be called from dispatchables.
+    pub struct ColorWeightedVotingPower<T, W, I = ()>(PhantomData<(T, W, I)>);
+
+    impl<T: Config<I>, I: 'static, W: Convert<Color, u64>> VotingPower<T::AccountId> for ColorWeightedVotingPower<T, W, I>{
+        fn voting_power(who: &T::AccountId) -> u64{
+            OwnerOfCollectibles::<T, I>::iter_key_prefix(who)
+                .filter_map(CollectibleMap::<T, I>::get)
+                .fold(0u64, |power, collectible| power.saturating_add(W::convert(collectible.color)))
+        }
+
+        fn total_voting_power() -> u64{
+            CollectibleMap::<T, I>::iter_values()
+                .fold(0u64, |power, collectible| power.saturating_add(W::convert(collectible.color)))
+        }
+    }
+
+    // Read-only helpers backing the `CollectiblesApi` runtime API. FRAME at polkadot-v1.0.0 has no view functions, so the runtime API is the way to reach them
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        pub fn collectibles_of(account: &T::AccountId) -> Vec<[u8; 16]>{
+            OwnerOfCollectibles::<T, I>::iter_key_prefix(account).collect()
+        }
+
+        pub fn owner_of(collectible_id: [u8; 16]) -> Option<T::AccountId>{
+            CollectibleMap::<T, I>::get(&collectible_id).map(|collectible| collectible.owner)
+        }
+
+        pub fn listings() -> Vec<([u8; 16], BalanceOf<T, I>)>{
+            CollectibleMap::<T, I>::iter_values()
+                .filter_map(|collectible| Self::listing_quote(&collectible).map(|price| (collectible.unique_id, price)))
+                .collect()
+        }
+
+        // The price a collectible can be bought at right now, `None` if it isn't on sale, its listing expired or it's
+        // flagged
+        fn listing_quote(collectible: &Collectible<T, I>) -> Option<BalanceOf<T, I>>{
+            if FlaggedCollectibles::<T, I>::contains_key(collectible.unique_id) || Self::listing_expired(collectible.unique_id){
+                return None;
+            }
+            Self::quote(collectible)
+        }
+
+        pub fn details(collectible_id: [u8; 16]) -> Option<Collectible<T, I>>{
+            CollectibleMap::<T, I>::get(&collectible_id)
+        }
+
+        /// Ids beyond `MaxDetailsQuery` are ignored. The result keeps the order of `collectible_ids`
+        pub fn details_many(collectible_ids: Vec<[u8; 16]>) -> Vec<Option<Collectible<T, I>>>{
+            collectible_ids
+                .into_iter()
+                .take(T::MaxDetailsQuery::get() as usize)
+                .map(CollectibleMap::<T, I>::get)
+                .collect()
+        }
+
+        /// The ERC-721 token id of a collectible
+        pub fn token_id(collectible_id: [u8; 16]) -> u128{
+            u128::from_be_bytes(collectible_id)
+        }
+
+        /// The collectible id behind an ERC-721 token id
+        pub fn collectible_id_of(token_id: u128) -> [u8; 16]{
+            token_id.to_be_bytes()
+        }
+
+        pub fn manager_of(collectible_id: [u8; 16]) -> Option<T::AccountId>{
+            Managers::<T, I>::get(collectible_id)
+        }
+
+        pub fn metadata_of(collectible_id: [u8; 16]) -> Option<Vec<u8>>{
+            Metadata::<T, I>::get(collectible_id).map(BoundedVec::into_inner)
+        }
+
+        pub fn is_stolen(collectible_id: [u8; 16]) -> bool{
+            StolenCollectibles::<T, I>::contains_key(collectible_id)
+        }
+
+        /// Whether the collectible was minted by a currently verified creator
+        pub fn is_verified(collectible_id: [u8; 16]) -> bool{
+            CollectibleMap::<T, I>::get(&collectible_id)
+                .map_or(false, |collectible| VerifiedCreators::<T, I>::contains_key(&collectible.creator))
+        }
+
+        pub fn flag_of(collectible_id: [u8; 16]) -> Option<T::Hash>{
+            FlaggedCollectibles::<T, I>::get(collectible_id)
+        }
+
+        /// Reference-priced collectibles are quoted in the native currency at the current oracle rate
+        pub fn price_of(collectible_id: [u8; 16]) -> Option<BalanceOf<T, I>>{
+            CollectibleMap::<T, I>::get(&collectible_id).and_then(|collectible| Self::quote(&collectible))
+        }
+
+        /// The account entitled to use the collectible: its renter during a rental, its owner otherwise
+        pub fn user_of(collectible_id: [u8; 16]) -> Option<T::AccountId>{
+            match Rentals::<T, I>::get(&collectible_id){
+                Some((renter, until)) if frame_system::Pallet::<T>::block_number() <= until => Some(renter),
+                _ => Self::owner_of(collectible_id),
+            }
+        }
+
+        pub fn backing_of(collectible_id: [u8; 16]) -> Option<BalanceOf<T, I>>{
+            Backing::<T, I>::get(&collectible_id)
+        }
+
+        pub fn total_supply() -> u64{
+            CollectibleMap::<T, I>::count() as u64
+        }
+
+        pub fn content_hash_of(collectible_id: [u8; 16]) -> Option<[u8; 32]>{
+            ContentHashes::<T, I>::get(collectible_id)
+        }
+
+        pub fn total_burned() -> u64{
+            BurnedCount::<T, I>::get()
+        }
+
+        pub fn owned_count(account: &T::AccountId) -> u32{
+            OwnedCount::<T, I>::get(account)
+        }
+
+        pub fn trade_history(account: &T::AccountId) -> Vec<Trade<T, I>>{
+            TradeHistory::<T, I>::get(account).into_inner()
+        }
+
+        pub fn color_supply(color: Color) -> u32{
+            ColorSupply::<T, I>::get(color)
+        }
+
+        /// Page through the collectibles of a color, following the storage order of `CollectiblesByColor`
+        pub fn collectibles_of_color_paged(
+            color: Color,
+            cursor: Option<[u8; 16]>,
+            limit: u32
+        ) -> (Vec<[u8; 16]>, Option<[u8; 16]>){
+            let collectibles = match cursor {
+                Some(cursor) => CollectiblesByColor::<T, I>::iter_key_prefix_from(
+                    color,
+                    CollectiblesByColor::<T, I>::hashed_key_for(color, cursor)
+                ),
+                None => CollectiblesByColor::<T, I>::iter_key_prefix(color),
+            };
+            let page: Vec<[u8; 16]> = collectibles.take(limit as usize).collect();
+            let next_cursor = if page.len() == limit as usize { page.last().copied() } else { None };
+            (page, next_cursor)
+        }
+
+        /// Built from a single pass over `CollectibleMap`, so every field refers to the same state
+        pub fn snapshot() -> IndexerSnapshot<BalanceOf<T, I>>{
+            let mut per_color: Vec<(Color, u64)> = [Color::Red, Color::Yellow, Color::Blue, Color::Green]
+                .into_iter()
+                .map(|color| (color, 0))
+                .collect();
+            let mut listings = Vec::new();
+            let mut metadata_hashes = Vec::new();
+            let mut content_hashes = Vec::new();
+            for collectible in CollectibleMap::<T, I>::iter_values(){
+                let collectible_id = collectible.unique_id;
+                if let Some((_, count)) = per_color.iter_mut().find(|(color, _)| *color == collectible.color){
+                    *count += 1;
+                }
+                if let Some(price) = Self::listing_quote(&collectible){
+                    listings.push((collectible_id, price));
+                }
+                if let Some(metadata) = Metadata::<T, I>::get(collectible_id){
+                    metadata_hashes.push((collectible_id, sp_io::hashing::blake2_256(&metadata)));
+                }
+                if let Some(hash) = ContentHashes::<T, I>::get(collectible_id){
+                    content_hashes.push((collectible_id, hash));
+                }
+            }
+            IndexerSnapshot {
+                total_supply: CollectibleMap::<T, I>::count() as u64,
+                per_color,
+                listings,
+                metadata_hashes,
+                content_hashes
+            }
+        }
+
+        /// Page through the collectibles of `account`, following the storage order of `OwnerOfCollectibles`, which doesn't depend on insertions or removals.
+        /// The returned cursor is the last id of the page and is `None` once there's nothing left
+        pub fn collectibles_of_paged(
+            account: &T::AccountId,
+            cursor: Option<[u8; 16]>,
+            limit: u32
+        ) -> (Vec<[u8; 16]>, Option<[u8; 16]>){
+            let owned = match cursor {
+                Some(cursor) => OwnerOfCollectibles::<T, I>::iter_key_prefix_from(
+                    account,
+                    OwnerOfCollectibles::<T, I>::hashed_key_for(account, cursor)
+                ),
+                None => OwnerOfCollectibles::<T, I>::iter_key_prefix(account),
+            };
+            let page: Vec<[u8; 16]> = owned.take(limit as usize).collect();
+            let next_cursor = if page.len() == limit as usize { page.last().copied() } else { None };
+            (page, next_cursor)
+        }
+
+        /// Page through every collectible on sale, following the storage order of `CollectibleMap`, which doesn't depend on insertions or removals.
+        /// Prices are quoted as by `listings`, which expired and flagged listings are left out of
+        pub fn all_listed_paged(
+            cursor: Option<[u8; 16]>,
+            limit: u32
+        ) -> (Vec<([u8; 16], BalanceOf<T, I>)>, Option<[u8; 16]>){
+            let collectibles = match cursor {
+                Some(cursor) => CollectibleMap::<T, I>::iter_from(CollectibleMap::<T, I>::hashed_key_for(cursor)),
+                None => CollectibleMap::<T, I>::iter(),
+            };
+            let page: Vec<([u8; 16], BalanceOf<T, I>)> = collectibles
+                .filter_map(|(id, collectible)| Self::listing_quote(&collectible).map(|price| (id, price)))
+                .take(limit as usize)
+                .collect();
+            let next_cursor = if page.len() == limit as usize { page.last().map(|(id, _)| *id) } else { None };
+            (page, next_cursor)
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I>{
+        type Call = Call<T, I>;
+
+        // Only the settlement of ended buyouts, the delisting of listings that did expire and faucet claims within the rate
+        // limits are accepted, once each
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity{
+            match call{
+                Call::settle_buyout_unsigned { collectible_id } => {
+                    ensure!(Self::buyout_over(*collectible_id), InvalidTransaction::Stale);
+                    ValidTransaction::with_tag_prefix("CollectiblesSettleBuyout")
+                        .and_provides(collectible_id)
+                        .longevity(5)
+                        .propagate(true)
+                        .build()
+                },
+                Call::delist_expired { collectible_id } => {
+                    ensure!(Self::listing_expired(*collectible_id), InvalidTransaction::Stale);
+                    ValidTransaction::with_tag_prefix("CollectiblesDelistExpired")
+                        .and_provides(collectible_id)
+                        .longevity(5)
+                        .propagate(true)
+                        .build()
+                },
+                Call::claim_dev_collectible { who } => {
+                    Self::ensure_faucet_claimable(who).map_err(|_| InvalidTransaction::ExhaustsResources)?;
+                    ValidTransaction::with_tag_prefix("CollectiblesFaucet")
+                        .and_provides(who)
+                        .longevity(5)
+                        .propagate(true)
+                        .build()
+                },
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I>{
+        /// Mint a collectible to the caller. `royalty` overrides the caller's default royalty for it, within the allowed
+        /// range. `content_hash` is the hash of its off-chain content, which can't be changed afterwards
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_collectible())]
+        pub fn create_collectible(
+            origin: OriginFor<T>,
+            royalty: Option<Royalty<T::AccountId>>,
+            content_hash: Option<[u8; 32]>
+        ) -> DispatchResult{
+            let sender = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_royalty_allowed(&royalty)?;
+
+            let (unique_id, color) = Self::gen_unique_id();
+
+            Self::mint(&sender, unique_id, color)?;
+
+            if let Some(royalty) = royalty{
+                Royalties::<T, I>::insert(unique_id, &royalty);
+                Self::deposit_event(Event::RoyaltySet { collectible: unique_id, old: None, royalty: Some(royalty) });
+            }
+            if let Some(hash) = content_hash{
+                ContentHashes::<T, I>::insert(unique_id, hash);
+                Self::deposit_event(Event::ContentHashSet { collectible: unique_id, hash });
+            }
+
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::destroy_collectible(T::MaxImportedAttributes::get(), T::StringLimit::get()))]
+        pub fn destroy_collectible(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResultWithPostInfo{
+            let sender = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == sender, Error::<T, I>::NotOwner);
+            // A buyer may be paying for it in this very block
+            ensure!(collectible.price.is_none(), Error::<T, I>::CollectibleOnSale);
+            Self::ensure_not_touched(collectible_id)?;
+
+            let (deposit_refunded, attributes, metadata) = Self::burn(&collectible)?;
+
+            Self::deposit_event(Event::CollectibleDestroyed { collectible: collectible_id, owner: sender, deposit_refunded });
+            Ok(Some(T::WeightInfo::destroy_collectible(attributes, metadata)).into())
+        }
+
+        /// Transfer a collectible to another account.
+        /// Any account that holds a collectible can send it to another account. 
+        /// Transfer resets the price of the collectible, marking it not for sale.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::transfer(Pallet::<T, I>::max_weighed_owned()))]
+        pub fn transfer(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            collectible_id: [u8; 16]
+        ) -> DispatchResultWithPostInfo {
+            // Make sure the caller is from a signed origin
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == from, Error::<T, I>::NotOwner);
+            let owned = OwnedCount::<T, I>::get(&to);
+            Self::charge_transfer_tax(&collectible, &from)?;
+            Self::enforce_royalty(collectible_id, &from, &to)?;
+            Self::do_transfer(collectible_id, to)?;
+            Ok(Some(T::WeightInfo::transfer(owned.min(Self::max_weighed_owned()))).into())
+        }
+
+        /// List a collectible at `new_price`. On co-owned collectibles it approves the price on behalf of the caller, and
+        /// the listing takes effect once co-owners holding more than the threshold of the shares approve the same price
+        /// within `ApprovalPeriod`
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_price())]
+        pub fn set_price(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            new_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            Self::ensure_relist_allowed(&collectible)?;
+            // Co-owned collectibles are listed once enough co-owners set the same price
+            match CoOwned::<T, I>::get(&collectible_id){
+                Some(co_ownership) => {
+                    if !Self::approve_listing(collectible_id, &co_ownership, from, new_price)?{
+                        return Ok(());
+                    }
+                },
+                None => Self::ensure_manages(&collectible, &from)?,
+            }
+            collectible.price = Some(new_price);
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            Self::cap_listing_duration(collectible_id);
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::PriceSet { collectible: collectible_id, price: new_price });
+            Ok(())
+        }
+
+        /// Delist a collectible. On co-owned collectibles it approves delisting on behalf of the caller, and the listing
+        /// is withdrawn once co-owners holding more than the threshold of the shares approve it within `ApprovalPeriod`
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_from_market())]
+        pub fn remove_from_market(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            // Co-owned collectibles are delisted once enough co-owners approve it
+            match CoOwned::<T, I>::get(&collectible_id){
+                Some(co_ownership) => {
+                    if !Self::approve_delisting(collectible_id, &co_ownership, from)?{
+                        return Ok(());
+                    }
+                },
+                None => Self::ensure_manages(&collectible, &from)?,
+            }
+            collectible.price = None;
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::NotLongerOnSale { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Buy a listed collectible. Unless `keep_alive`, the buyer can spend their whole balance, existential deposit
+        /// included, e.g. to consolidate accounts. The order fails after the `valid_until` block, if any, so it can't
+        /// execute long after the buyer changed their mind
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::buy(Pallet::<T, I>::max_weighed_owned()))]
+        pub fn buy(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            offered_price: BalanceOf<T, I>,
+            keep_alive: bool,
+            valid_until: Option<BlockNumberFor<T>>
+        ) -> DispatchResultWithPostInfo{
+            let buyer = ensure_signed(origin)?; // Ensure that the buyer signed the transaction
+            ensure!(
+                valid_until.map_or(true, |until| frame_system::Pallet::<T>::block_number() <= until),
+                Error::<T, I>::OrderExpired
+            );
+            let preservation = if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+            let owned = OwnedCount::<T, I>::get(&buyer);
+            Self::buy_listed(collectible_id, buyer, offered_price, preservation)?;
+            Ok(Some(T::WeightInfo::buy(owned.min(Self::max_weighed_owned()))).into())
+        }
+
+        /// Transfer a collectible on behalf of its owner, for court-ordered or recovery transfers.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::force_transfer())]
+        pub fn force_transfer(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            from: T::AccountId,
+            to: T::AccountId
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == from, Error::<T, I>::NotOwner);
+            let (collectible, from) = Self::pre_transfer(collectible_id, &to)?;
+            Self::post_transfer(&collectible, &from, &to)?;
+            Self::deposit_event(Event::ForceTransferred { from, to, collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Destroy a collectible regardless of its owner, e.g. to remove references to illegal content. Whatever locks it
+        /// is unwound first: loans, rentals, stakes, options, gifts and airdrops are cancelled, and escrowed funds and
+        /// buyout bids are refunded.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::force_destroy(T::MaxImportedAttributes::get(), T::StringLimit::get()))]
+        pub fn force_destroy(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResultWithPostInfo{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::release_lock(collectible_id)?;
+            let (deposit_refunded, attributes, metadata) = Self::burn(&collectible)?;
+            Self::deposit_event(Event::ForceDestroyed { owner: collectible.owner, collectible: collectible_id, deposit_refunded });
+            Ok(Some(T::WeightInfo::force_destroy(attributes, metadata)).into())
+        }
+
+        /// Pause or unpause the pallet. Owners can still retire their collectibles from the market while paused.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_paused())]
+        pub fn set_paused(
+            origin: OriginFor<T>,
+            paused: bool
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Paused::<T, I>::put(paused);
+            if paused {
+                Self::deposit_event(Event::Paused);
+            }
+            else{
+                Self::deposit_event(Event::Unpaused);
+            }
+            Ok(())
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_parameter())]
+        pub fn set_parameter(
+            origin: OriginFor<T>,
+            parameter: Parameter<BalanceOf<T, I>>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            match parameter {
+                Parameter::MaximumOwned(maximum_owned) => MaximumOwned::<T, I>::put(maximum_owned),
+                Parameter::SaleBurn(sale_burn) => SaleBurn::<T, I>::put(sale_burn),
+                Parameter::MintersNeedIdentity(required) => MintersNeedIdentity::<T, I>::put(required),
+                Parameter::BuyersNeedIdentity(required) => BuyersNeedIdentity::<T, I>::put(required),
+                Parameter::ItemDeposit(deposit) => ItemDeposit::<T, I>::put(deposit),
+                Parameter::NameDeposit(deposit) => NameDeposit::<T, I>::put(deposit),
+                Parameter::NoteDeposit(deposit) => NoteDeposit::<T, I>::put(deposit),
+            }
+            Self::deposit_event(Event::ParameterSet { parameter });
+            Ok(())
+        }
+
+        /// Flag a collectible as stolen. It's retired from the market and only `force_transfer` can move it, e.g. back to the victim.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::report_stolen())]
+        pub fn report_stolen(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            collectible.price = None;
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            StolenCollectibles::<T, I>::insert(collectible_id, ());
+            Self::deposit_event(Event::ReportedStolen { collectible: collectible_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::clear_stolen())]
+        pub fn clear_stolen(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleNotStolen);
+            StolenCollectibles::<T, I>::remove(collectible_id);
+            Self::deposit_event(Event::StolenFlagCleared { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Flag a collectible, hiding it from the listings. Flagging it again replaces the reason.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::flag_item())]
+        pub fn flag_item(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            reason: T::Hash
+        ) -> DispatchResult{
+            T::ModeratorOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(CollectibleMap::<T, I>::contains_key(&collectible_id), Error::<T, I>::NoCollectible);
+            FlaggedCollectibles::<T, I>::insert(collectible_id, reason);
+            Self::deposit_event(Event::CollectibleFlagged { collectible: collectible_id, reason });
+            Ok(())
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::clear_flag())]
+        pub fn clear_flag(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            T::ModeratorOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(FlaggedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::NotFlagged);
+            FlaggedCollectibles::<T, I>::remove(collectible_id);
+            Self::deposit_event(Event::FlagCleared { collectible: collectible_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::verify_creator())]
+        pub fn verify_creator(
+            origin: OriginFor<T>,
+            creator: T::AccountId
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(!VerifiedCreators::<T, I>::contains_key(&creator), Error::<T, I>::AlreadyVerified);
+            VerifiedCreators::<T, I>::insert(&creator, ());
+            Self::deposit_event(Event::CreatorVerified { creator });
+            Ok(())
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::unverify_creator())]
+        pub fn unverify_creator(
+            origin: OriginFor<T>,
+            creator: T::AccountId
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(VerifiedCreators::<T, I>::contains_key(&creator), Error::<T, I>::NotVerified);
+            VerifiedCreators::<T, I>::remove(&creator);
+            Self::deposit_event(Event::CreatorUnverified { creator });
+            Ok(())
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_transfers_enabled())]
+        pub fn set_transfers_enabled(
+            origin: OriginFor<T>,
+            enabled: bool
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            TransfersEnabled::<T, I>::put(enabled);
+            Self::deposit_event(Event::TransfersToggled { enabled });
+            Ok(())
+        }
+
+        /// List a collectible for sale priced in `asset` instead of `Currency`. It can only be bought with `buy_with_asset`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::set_price_in_asset())]
+        pub fn set_price_in_asset(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            asset: AssetIdOf<T, I>,
+            new_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            Self::ensure_relist_allowed(&collectible)?;
+            collectible.price = Some(new_price);
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            ListingAssets::<T, I>::insert(collectible_id, asset);
+            Self::cap_listing_duration(collectible_id);
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::PriceSetInAsset { collectible: collectible_id, asset, price: new_price });
+            Ok(())
+        }
+
+        /// Buy a collectible listed with `set_price_in_asset`, paying in the asset it's priced in, or paying in any of the
+        /// assets quoted with `set_asset_quotes`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::buy_with_asset())]
+        pub fn buy_with_asset(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            asset: AssetIdOf<T, I>,
+            offered_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let buyer = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            let listed_price = Self::listed_price(&collectible).ok_or(Error::<T, I>::CollectibleNotForSale)?;
+            let price = if ListingAssets::<T, I>::get(&collectible_id) == Some(asset){
+                listed_price
+            }
+            else{
+                AssetQuotes::<T, I>::get(&collectible_id)
+                    .into_iter()
+                    .find_map(|(quoted_asset, price)| (quoted_asset == asset).then_some(price))
+                    .ok_or(Error::<T, I>::WrongPaymentAsset)?
+            };
+            ensure!(offered_price >= price, Error::<T, I>::OfferedPriceTooLow);
+            Self::ensure_not_touched(collectible_id)?;
+            Self::do_buy_in(collectible_id, buyer, Some(asset), offered_price, Preservation::Preserve)?;
+            Ok(())
+        }
+
+        /// List a collectible for sale priced in the reference unit of `PriceOracle`, e.g. USD. Buyers pay the native
+        /// equivalent at the rate of the moment, so long-lived listings don't go stale when the native currency moves.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_reference_price())]
+        pub fn set_reference_price(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            new_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            Self::ensure_relist_allowed(&collectible)?;
+            collectible.price = Some(new_price);
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            ReferencePriced::<T, I>::insert(collectible_id, ());
+            Self::cap_listing_duration(collectible_id);
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::ReferencePriceSet { collectible: collectible_id, price: new_price });
+            Ok(())
+        }
+
+        /// Quote a listed collectible in other assets, so buyers can pay any of them through `buy_with_asset`.
+        /// The quotes replace the previous ones and are cleared along with the listing.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_asset_quotes(quotes.len() as u32))]
+        pub fn set_asset_quotes(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            quotes: AssetQuotesOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            for (index, (asset, _)) in quotes.iter().enumerate(){
+                ensure!(quotes.iter().skip(index + 1).all(|(other, _)| other != asset), Error::<T, I>::DuplicateQuote);
+            }
+            if quotes.is_empty(){
+                AssetQuotes::<T, I>::remove(collectible_id);
+            }
+            else{
+                AssetQuotes::<T, I>::insert(collectible_id, &quotes);
+            }
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::AssetQuotesSet { collectible: collectible_id, quotes });
+            Ok(())
+        }
+
+        /// Lock a collectible and mint `total_shares` of a new asset `asset` to its owner, so several accounts can own it.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::fractionalize())]
+        pub fn fractionalize(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            asset: AssetIdOf<T, I>,
+            total_shares: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(collectible.price.is_none(), Error::<T, I>::CollectibleOnSale);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            ensure!(!total_shares.is_zero(), Error::<T, I>::NoShares);
+
+            Self::lock_for(collectible_id, LockReason::Fractionalized)?;
+            T::Assets::create(asset, Self::account_id(), false, One::one())?;
+            T::Assets::mint_into(asset, &owner, total_shares)?;
+            Fractionalized::<T, I>::insert(collectible_id, (asset, total_shares));
+            Self::deposit_event(Event::CollectibleFractionalized { collectible: collectible_id, asset, shares: total_shares });
+            Ok(())
+        }
+
+        /// Burn all the shares of a fractionalized collectible to unlock it and take it over. The asset of the shares is
+        /// destroyed.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::unify())]
+        pub fn unify(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(!Buyouts::<T, I>::contains_key(&collectible_id), Error::<T, I>::BuyoutOngoing);
+            let (asset, total_shares) = Fractionalized::<T, I>::take(&collectible_id).ok_or(Error::<T, I>::NotFractionalized)?;
+            ensure!(T::Assets::balance(asset, &who) >= total_shares, Error::<T, I>::InsufficientShares);
+            T::Assets::burn_from(asset, &who, total_shares, Precision::Exact, Fortitude::Polite)?;
+            Self::destroy_shares(asset)?;
+            Self::unlock_for(collectible_id, LockReason::Fractionalized)?;
+            if Self::owner_of(collectible_id).as_ref() != Some(&who){
+                Self::do_transfer(collectible_id, who.clone())?;
+            }
+            Self::deposit_event(Event::CollectibleUnified { collectible: collectible_id, owner: who });
+            Ok(())
+        }
+
+        /// Bid to buy out a fractionalized collectible. Only shareholders can open a buyout; afterwards anyone can outbid
+        /// the leading bid until `BuyoutPeriod` blocks go by without a new one. The bid is kept by the pallet and given
+        /// back when outbid.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::bid_buyout(1))]
+        pub fn bid_buyout(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            bid: BalanceOf<T, I>
+        ) -> DispatchResultWithPostInfo{
+            let bidder = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (asset, _) = Fractionalized::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotFractionalized)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let leading = Buyouts::<T, I>::get(&collectible_id);
+            let outbid = leading.is_some() as u32;
+            match leading{
+                Some(leading) => {
+                    ensure!(now <= leading.end, Error::<T, I>::BuyoutOver);
+                    ensure!(bid > leading.bid, Error::<T, I>::BidTooLow);
+                    T::Currency::transfer(&Self::account_id(), &leading.bidder, leading.bid, Preservation::Expendable)?;
+                    EndingBuyouts::<T, I>::remove(leading.end.saturating_add(One::one()), collectible_id);
+                },
+                None => {
+                    ensure!(!bid.is_zero(), Error::<T, I>::BidTooLow);
+                    ensure!(!T::Assets::balance(asset, &bidder).is_zero(), Error::<T, I>::NotShareholder);
+                }
+            }
+            T::Currency::transfer(&bidder, &Self::account_id(), bid, Preservation::Preserve)?;
+            let end = now.saturating_add(T::BuyoutPeriod::get());
+            Buyouts::<T, I>::insert(collectible_id, Buyout { bidder: bidder.clone(), bid, end });
+            EndingBuyouts::<T, I>::insert(end.saturating_add(One::one()), collectible_id, ());
+            Self::deposit_event(Event::BuyoutBid { collectible: collectible_id, bidder, bid });
+            Ok(Some(T::WeightInfo::bid_buyout(outbid)).into())
+        }
+
+        /// Close a buyout once its bidding period is over. The collectible goes to the winning bidder and its bid becomes
+        /// redeemable by the shareholders with `redeem_shares`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::settle_buyout())]
+        pub fn settle_buyout(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_settle_buyout(collectible_id)
+        }
+
+        /// Burn all the caller's shares of a bought out collectible for their pro-rata part of the proceeds. The asset of
+        /// the shares is destroyed with the last ones.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::redeem_shares())]
+        pub fn redeem_shares(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (asset, shares_left, proceeds_left) = BuyoutProceeds::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NothingToRedeem)?;
+            let shares = T::Assets::balance(asset, &who).min(shares_left);
+            ensure!(!shares.is_zero(), Error::<T, I>::NothingToRedeem);
+            let payout = if shares == shares_left{
+                proceeds_left
+            }
+            else{
+                multiply_by_rational_with_rounding(
+                    proceeds_left.saturated_into(),
+                    shares.saturated_into(),
+                    shares_left.saturated_into(),
+                    Rounding::Down
+                ).map(|payout| payout.saturated_into()).unwrap_or_else(Zero::zero)
+            };
+            T::Assets::burn_from(asset, &who, shares, Precision::Exact, Fortitude::Polite)?;
+            T::Currency::transfer(&Self::account_id(), &who, payout, Preservation::Expendable)?;
+            if shares == shares_left{
+                BuyoutProceeds::<T, I>::remove(collectible_id);
+                Self::destroy_shares(asset)?;
+            }
+            else{
+                BuyoutProceeds::<T, I>::insert(collectible_id, (asset, shares_left - shares, proceeds_left - payout));
+            }
+            Self::deposit_event(Event::SharesRedeemed { collectible: collectible_id, who, shares, payout });
+            Ok(())
+        }
+
+        /// Create a collectible backed by `backing`, which is moved to a pot of its own and paid to whoever destroys it.
+        /// It gives the collectible an on-chain floor value.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::create_backed_collectible())]
+        pub fn create_backed_collectible(
+            origin: OriginFor<T>,
+            backing: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let sender = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(backing >= T::Currency::minimum_balance(), Error::<T, I>::BackingTooLow);
+
+            let (unique_id, color) = Self::gen_unique_id();
+            Self::mint(&sender, unique_id, color)?;
+            T::Currency::transfer(&sender, &Self::backing_account(unique_id), backing, Preservation::Preserve)?;
+            Backing::<T, I>::insert(unique_id, backing);
+
+            Self::deposit_event(Event::BackedCollectibleCreated { collectible: unique_id, owner: sender, backing });
+            Ok(())
+        }
+
+        /// Offer a collectible as collateral to borrow `amount`, to be paid back as `repayment` within `duration` blocks
+        /// of the loan being funded. The collectible is locked until then.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::request_loan())]
+        pub fn request_loan(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            amount: BalanceOf<T, I>,
+            repayment: BalanceOf<T, I>,
+            duration: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let borrower = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == borrower, Error::<T, I>::NotOwner);
+            ensure!(collectible.price.is_none(), Error::<T, I>::CollectibleOnSale);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+
+            Self::lock_for(collectible_id, LockReason::Loan)?;
+            Loans::<T, I>::insert(collectible_id, Loan { borrower: borrower.clone(), amount, repayment, duration, lender: None });
+            Self::deposit_event(Event::LoanRequested { collectible: collectible_id, borrower, amount, repayment, duration });
+            Ok(())
+        }
+
+        /// Withdraw a loan request nobody has funded yet, unlocking the collectible.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::cancel_loan())]
+        pub fn cancel_loan(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let loan = Loans::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoLoan)?;
+            ensure!(loan.borrower == who, Error::<T, I>::NotBorrower);
+            ensure!(loan.lender.is_none(), Error::<T, I>::LoanFunded);
+            Loans::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::Loan)?;
+            Self::deposit_event(Event::LoanCancelled { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Lend the requested amount to the borrower, who can't be the lender. From now on the borrower has the loan's
+        /// duration to repay it.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::fund_loan())]
+        pub fn fund_loan(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let lender = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut loan = Loans::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoLoan)?;
+            ensure!(loan.lender.is_none(), Error::<T, I>::LoanFunded);
+            ensure!(loan.borrower != lender, Error::<T, I>::BorrowerIsLender);
+            T::Currency::transfer(&lender, &loan.borrower, loan.amount, Preservation::Preserve)?;
+            let deadline = frame_system::Pallet::<T>::block_number().saturating_add(loan.duration);
+            loan.lender = Some((lender.clone(), deadline));
+            Loans::<T, I>::insert(collectible_id, loan);
+            Self::deposit_event(Event::LoanFunded { collectible: collectible_id, lender, deadline });
+            Ok(())
+        }
+
+        /// Pay a funded loan back to the lender before its deadline, unlocking the collateral.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::repay_loan())]
+        pub fn repay_loan(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let loan = Loans::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoLoan)?;
+            ensure!(loan.borrower == who, Error::<T, I>::NotBorrower);
+            let (lender, deadline) = loan.lender.ok_or(Error::<T, I>::LoanNotFunded)?;
+            ensure!(frame_system::Pallet::<T>::block_number() <= deadline, Error::<T, I>::LoanExpired);
+            T::Currency::transfer(&who, &lender, loan.repayment, Preservation::Preserve)?;
+            Loans::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::Loan)?;
+            Self::deposit_event(Event::LoanRepaid { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Hand the collateral of a loan that wasn't repaid in time over to its lender. Anyone can trigger it. Trading
+        /// restrictions on the collateral, such as disabled transfers or `MaximumOwned`, don't apply.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::claim_collateral())]
+        pub fn claim_collateral(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let loan = Loans::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoLoan)?;
+            let (lender, deadline) = loan.lender.ok_or(Error::<T, I>::LoanNotFunded)?;
+            ensure!(frame_system::Pallet::<T>::block_number() > deadline, Error::<T, I>::LoanNotExpired);
+            Loans::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::Loan)?;
+            if lender != loan.borrower{
+                Self::seize_collateral(collectible_id, &lender)?;
+            }
+            Self::deposit_event(Event::LoanDefaulted { collectible: collectible_id, lender });
+            Ok(())
+        }
+
+        /// Offer a collectible for rent at `price_per_period` for up to `max_periods` `RentalPeriod`s at a time.
+        /// Renters get to use it while the owner keeps it.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::list_for_rent())]
+        pub fn list_for_rent(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            price_per_period: BalanceOf<T, I>,
+            max_periods: u32
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            ensure!(max_periods > 0, Error::<T, I>::InvalidRentalPeriods);
+            RentalOffers::<T, I>::insert(collectible_id, (price_per_period, max_periods));
+            Self::deposit_event(Event::ListedForRent { collectible: collectible_id, price_per_period, max_periods });
+            Ok(())
+        }
+
+        /// Stop offering a collectible for rent. Not while it's rented, so the renter keeps its rent-to-own terms and
+        /// credit until the rental is over.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::unlist_for_rent())]
+        pub fn unlist_for_rent(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(RentalOffers::<T, I>::contains_key(&collectible_id), Error::<T, I>::NotForRent);
+            ensure!(!Rentals::<T, I>::contains_key(&collectible_id), Error::<T, I>::RentalOngoing);
+            Self::clear_rental_offer(collectible_id);
+            Self::deposit_event(Event::RentListingRemoved { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Rent a collectible for `periods` `RentalPeriod`s, paying the owner upfront. The collectible is locked
+        /// against transfers until the term is over.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::rent())]
+        pub fn rent(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            periods: u32
+        ) -> DispatchResult{
+            let renter = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (price_per_period, max_periods) = RentalOffers::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotForRent)?;
+            ensure!(periods > 0 && periods <= max_periods, Error::<T, I>::InvalidRentalPeriods);
+            let owner = Self::owner_of(collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(owner != renter, Error::<T, I>::TransferToSelf);
+
+            Self::lock_for(collectible_id, LockReason::Rental)?;
+            let cost = price_per_period.saturating_mul(periods.into());
+            T::Currency::transfer(&renter, &owner, cost, Preservation::Preserve)?;
+            Self::accrue_rent_credit(collectible_id, &renter, cost);
+            let until = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::RentalPeriod::get().saturating_mul(periods.into()));
+            Rentals::<T, I>::insert(collectible_id, (renter.clone(), until));
+            Self::queue_rental_expiry(collectible_id, until.saturating_add(One::one()));
+            Self::deposit_event(Event::Rented { collectible: collectible_id, renter, periods, until });
+            Ok(())
+        }
+
+        /// Close a rental whose term is over, unlocking the collectible. Anyone can trigger it, although expired rentals
+        /// are also closed automatically in idle time. Streaming rentals the renter can still pay for are renewed instead.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::end_rental())]
+        pub fn end_rental(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (renter, until) = Rentals::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotRented)?;
+            ensure!(frame_system::Pallet::<T>::block_number() > until, Error::<T, I>::RentalNotOver);
+            if Self::renew_rental(collectible_id, &renter, until){
+                return Ok(());
+            }
+            Self::close_rental(collectible_id, renter)
+        }
+
+        /// Let the renters of a collectible offered for rent buy it for `price`, with `share` of every rent they pay
+        /// counting towards it. The terms can't change while the collectible is rented.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::offer_rent_to_own())]
+        pub fn offer_rent_to_own(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            price: BalanceOf<T, I>,
+            share: Perbill
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(RentalOffers::<T, I>::contains_key(&collectible_id), Error::<T, I>::NotForRent);
+            ensure!(!Rentals::<T, I>::contains_key(&collectible_id), Error::<T, I>::RentalOngoing);
+            RentToOwnTerms::<T, I>::insert(collectible_id, (price, share));
+            Self::deposit_event(Event::RentToOwnOffered { collectible: collectible_id, price, share });
+            Ok(())
+        }
+
+        /// Buy the rent-to-own collectible the caller is renting, paying the purchase price minus the accrued credit.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::exercise_rent_to_own())]
+        pub fn exercise_rent_to_own(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let renter = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(Self::user_of(collectible_id).as_ref() == Some(&renter), Error::<T, I>::NotRented);
+            let (price, _) = RentToOwnTerms::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotRentToOwn)?;
+            let owner = Self::owner_of(collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            // The owner is also the user of a collectible that isn't rented
+            ensure!(owner != renter, Error::<T, I>::NotRented);
+            let credit = RentCredit::<T, I>::get(&collectible_id)
+                .filter(|(credited, _)| *credited == renter)
+                .map_or_else(Zero::zero, |(_, credit)| credit);
+            let paid = price.saturating_sub(credit);
+
+            T::Currency::transfer(&renter, &owner, paid, Preservation::Preserve)?;
+            Self::close_rental(collectible_id, renter.clone())?;
+            Self::do_transfer(collectible_id, renter.clone())?;
+            Self::deposit_event(Event::RentToOwnExercised { collectible: collectible_id, renter, paid });
+            Ok(())
+        }
+
+        /// Rent a collectible period by period, paying each one from `held`, which is held from the caller. The rental
+        /// is renewed automatically while the hold covers another period, up to the periods allowed by the offer.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::start_rent_stream())]
+        pub fn start_rent_stream(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            held: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let renter = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (price_per_period, max_periods) = RentalOffers::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotForRent)?;
+            ensure!(held >= price_per_period, Error::<T, I>::RentHoldTooLow);
+            let owner = Self::owner_of(collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(owner != renter, Error::<T, I>::TransferToSelf);
+
+            Self::lock_for(collectible_id, LockReason::Rental)?;
+            T::Currency::hold(&HoldReason::RentStream.into(), &renter, held)?;
+            // The first period is paid upfront
+            T::Currency::transfer_on_hold(
+                &HoldReason::RentStream.into(),
+                &renter,
+                &owner,
+                price_per_period,
+                Precision::Exact,
+                Restriction::Free,
+                Fortitude::Polite
+            )?;
+            Self::accrue_rent_credit(collectible_id, &renter, price_per_period);
+            let until = frame_system::Pallet::<T>::block_number().saturating_add(T::RentalPeriod::get());
+            Rentals::<T, I>::insert(collectible_id, (renter.clone(), until));
+            Self::queue_rental_expiry(collectible_id, until.saturating_add(One::one()));
+            RentStreams::<T, I>::insert(collectible_id, RentStream {
+                price_per_period,
+                held: held.saturating_sub(price_per_period),
+                periods_left: max_periods.saturating_sub(1),
+            });
+            Self::deposit_event(Event::Rented { collectible: collectible_id, renter, periods: 1, until });
+            Ok(())
+        }
+
+        /// Hold `amount` more from the caller to pay the upcoming periods of its streaming rental.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::top_up_rent_stream())]
+        pub fn top_up_rent_stream(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            amount: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let renter = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let (current_renter, _) = Rentals::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotRented)?;
+            ensure!(current_renter == renter, Error::<T, I>::NotRented);
+            let mut stream = RentStreams::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotStreaming)?;
+            T::Currency::hold(&HoldReason::RentStream.into(), &renter, amount)?;
+            stream.held = stream.held.saturating_add(amount);
+            let held = stream.held;
+            RentStreams::<T, I>::insert(collectible_id, stream);
+            Self::deposit_event(Event::RentStreamHeld { collectible: collectible_id, renter, held });
+            Ok(())
+        }
+
+        /// Stop renewing the caller's streaming rental, releasing its hold. The rental lasts until the end of the
+        /// period already paid.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::stop_rent_stream())]
+        pub fn stop_rent_stream(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let renter = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let (current_renter, _) = Rentals::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotRented)?;
+            ensure!(current_renter == renter, Error::<T, I>::NotRented);
+            let stream = RentStreams::<T, I>::take(&collectible_id).ok_or(Error::<T, I>::NotStreaming)?;
+            T::Currency::release(&HoldReason::RentStream.into(), &renter, stream.held, Precision::BestEffort)?;
+            Self::deposit_event(Event::RentStreamStopped { collectible: collectible_id, renter });
+            Ok(())
+        }
+
+        /// Stake a collectible, locking it. It accrues rewards every block until unstaked.
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::stake())]
+        pub fn stake(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let staker = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == staker, Error::<T, I>::NotOwner);
+            Self::lock_for(collectible_id, LockReason::Staked)?;
+            Stakes::<T, I>::insert(collectible_id, (staker.clone(), frame_system::Pallet::<T>::block_number()));
+            Self::deposit_event(Event::Staked { collectible: collectible_id, staker });
+            Ok(())
+        }
+
+        /// Claim the rewards a staked collectible has accrued, keeping it staked.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::claim_staking_rewards())]
+        pub fn claim_staking_rewards(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (staker, since) = Stakes::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotStaked)?;
+            ensure!(staker == who, Error::<T, I>::NotStaker);
+            Self::pay_staking_rewards(collectible_id, &staker, since)
+        }
+
+        /// Unstake a collectible, unlocking it. Its pending rewards are paid, unless the reward source can't afford
+        /// them, in which case they're forfeited rather than keeping the collectible locked.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::unstake())]
+        pub fn unstake(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (staker, since) = Stakes::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotStaked)?;
+            ensure!(staker == who, Error::<T, I>::NotStaker);
+            let _ = with_storage_layer(|| Self::pay_staking_rewards(collectible_id, &staker, since));
+            Stakes::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::Staked)?;
+            Self::deposit_event(Event::Unstaked { collectible: collectible_id, staker });
+            Ok(())
+        }
+
+        /// Deposit `amount` to be shared evenly among the collectibles existing now. Each share goes to whoever holds
+        /// the collectible at this block, even if it changes hands before the share is claimed. The remainder of the
+        /// division stays in the pot.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::deposit_revenue())]
+        pub fn deposit_revenue(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let depositor = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let supply = CollectibleMap::<T, I>::count();
+            ensure!(supply > 0, Error::<T, I>::NoHolders);
+            let per_item = amount / supply.into();
+            ensure!(!per_item.is_zero(), Error::<T, I>::DepositTooLow);
+            T::Currency::transfer(&depositor, &Self::revenue_account(), amount, Preservation::Preserve)?;
+            RevenuePerItem::<T, I>::mutate(|revenue_per_item| *revenue_per_item = revenue_per_item.saturating_add(per_item));
+            Self::deposit_event(Event::RevenueDeposited { depositor, amount, per_item });
+            Ok(())
+        }
+
+        /// Claim the revenue credited to the caller, settling first the shares of the given collectibles it holds.
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::claim_revenue(collectibles.len() as u32))]
+        pub fn claim_revenue(
+            origin: OriginFor<T>,
+            collectibles: BoundedVec<[u8; 16], T::MaxDetailsQuery>
+        ) -> DispatchResult{
+            let holder = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            for collectible_id in collectibles{
+                Self::ensure_owner(collectible_id, &holder)?;
+                Self::settle_revenue(collectible_id, &holder);
+            }
+            let amount = RevenueOwed::<T, I>::take(&holder);
+            ensure!(!amount.is_zero(), Error::<T, I>::NothingToRedeem);
+            T::Currency::transfer(&Self::revenue_account(), &holder, amount, Preservation::Expendable)?;
+            Self::deposit_event(Event::RevenueClaimed { holder, amount });
+            Ok(())
+        }
+
+        /// Donate `cut` of every sale to `beneficiary`, or stop donating if `None`.
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::set_charity_cut())]
+        pub fn set_charity_cut(
+            origin: OriginFor<T>,
+            charity_cut: Option<(T::AccountId, Perbill)>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            CharityCut::<T, I>::set(charity_cut.clone());
+            Self::deposit_event(Event::CharityCutSet { charity_cut });
+            Ok(())
+        }
+
+        /// Charge `tax` to the sender of every plain transfer, or stop charging it if `None`.
+        #[pallet::call_index(47)]
+        #[pallet::weight(T::WeightInfo::set_transfer_tax())]
+        pub fn set_transfer_tax(
+            origin: OriginFor<T>,
+            tax: Option<(TransferFee<BalanceOf<T, I>>, FeeRecipient)>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            TransferTax::<T, I>::set(tax);
+            Self::deposit_event(Event::TransferTaxSet { tax });
+            Ok(())
+        }
+
+        /// Set the royalty paid to `beneficiary` on every sale of a collectible the caller created, or remove it if
+        /// `None`. The rate can't exceed `MaxRoyalty`.
+        #[pallet::call_index(48)]
+        #[pallet::weight(T::WeightInfo::set_royalty())]
+        pub fn set_royalty(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            royalty: Option<Royalty<T::AccountId>>
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.creator == who, Error::<T, I>::NotCreator);
+            Self::ensure_royalty_allowed(&royalty)?;
+            let old = Royalties::<T, I>::mutate(collectible_id, |current| sp_std::mem::replace(current, royalty.clone()));
+            Self::deposit_event(Event::RoyaltySet { collectible: collectible_id, old, royalty });
+            Ok(())
+        }
+
+        /// Enforce royalties on plain transfers as `mode` says, or stop enforcing them if `None`.
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::set_royalty_enforcement())]
+        pub fn set_royalty_enforcement(
+            origin: OriginFor<T>,
+            mode: Option<RoyaltyEnforcement<BalanceOf<T, I>>>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            RoyaltyEnforcementMode::<T, I>::set(mode);
+            Self::deposit_event(Event::RoyaltyEnforcementSet { mode });
+            Ok(())
+        }
+
+        /// Let collectibles be transferred freely to `account` while royalties are enforced, or stop letting them.
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::set_royalty_exempt())]
+        pub fn set_royalty_exempt(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            exempt: bool
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            if exempt{
+                RoyaltyExempt::<T, I>::insert(&account, ());
+            }
+            else{
+                RoyaltyExempt::<T, I>::remove(&account);
+            }
+            Self::deposit_event(Event::RoyaltyExemptionSet { account, exempt });
+            Ok(())
+        }
+
+        /// Override the royalty of any collectible, or remove it if `None`, e.g. when its creator lost their key or the
+        /// beneficiary account is dead. The rate can't exceed `MaxRoyalty`.
+        #[pallet::call_index(51)]
+        #[pallet::weight(T::WeightInfo::force_set_royalty())]
+        pub fn force_set_royalty(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            royalty: Option<Royalty<T::AccountId>>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(CollectibleMap::<T, I>::contains_key(&collectible_id), Error::<T, I>::NoCollectible);
+            ensure!(
+                royalty.as_ref().map_or(true, |royalty| royalty.rate <= T::MaxRoyalty::get()),
+                Error::<T, I>::RoyaltyTooHigh
+            );
+            let old = Royalties::<T, I>::mutate(collectible_id, |current| sp_std::mem::replace(current, royalty.clone()));
+            Self::deposit_event(Event::RoyaltyForceSet { collectible: collectible_id, old, royalty });
+            Ok(())
+        }
+
+        /// Buy a collectible on behalf of an account on another chain, e.g. through XCM `Transact`. The account
+        /// `RemoteOrigin` resolves to pays, and owns the collectible here, so the remote buyer keeps control of it
+        /// through XCM or can reserve-transfer it to its chain.
+        #[pallet::call_index(52)]
+        #[pallet::weight(T::WeightInfo::remote_buy())]
+        pub fn remote_buy(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            offered_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let buyer = T::RemoteOrigin::ensure_origin(origin)?;
+            Self::buy_listed(collectible_id, buyer.clone(), offered_price, Preservation::Preserve)?;
+            Self::deposit_event(Event::BoughtRemotely { collectible: collectible_id, buyer });
+            Ok(())
+        }
+
+        /// Export a collectible to Asset Hub, locking it here. The event carries what's needed to re-create it under
+        /// `pallet_nfts`, owned by `beneficiary`.
+        #[pallet::call_index(53)]
+        #[pallet::weight(T::WeightInfo::export_collectible())]
+        pub fn export_collectible(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            beneficiary: T::AccountId
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(collectible.price.is_none(), Error::<T, I>::CollectibleOnSale);
+            Self::lock_for(collectible_id, LockReason::Exported)?;
+            Exported::<T, I>::insert(collectible_id, ());
+            Self::deposit_event(Event::CollectibleExported {
+                collectible: collectible_id,
+                color: collectible.color,
+                creator: collectible.creator,
+                beneficiary
+            });
+            Ok(())
+        }
+
+        /// Import back a collectible whose Asset Hub counterpart has been burned, releasing it to `owner`.
+        #[pallet::call_index(54)]
+        #[pallet::weight(T::WeightInfo::import_collectible())]
+        pub fn import_collectible(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            owner: T::AccountId
+        ) -> DispatchResult{
+            T::BridgeOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(Exported::<T, I>::take(&collectible_id).is_some(), Error::<T, I>::NotExported);
+            Self::unlock_for(collectible_id, LockReason::Exported)?;
+            if Self::owner_of(collectible_id).as_ref() != Some(&owner){
+                Self::do_transfer(collectible_id, owner.clone())?;
+            }
+            Self::deposit_event(Event::CollectibleImported { collectible: collectible_id, owner });
+            Ok(())
+        }
+
+        /// Approve `delegate` to transfer a collectible on behalf of its owner, or revoke the approval if `None`.
+        /// The approval is cleared when the collectible changes hands
+        #[pallet::call_index(55)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            delegate: Option<T::AccountId>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_approve(&owner, collectible_id, delegate)
+        }
+
+        /// Transfer a collectible of `from`, either by `from` itself or by the account they approved
+        #[pallet::call_index(56)]
+        #[pallet::weight(T::WeightInfo::transfer_from())]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let caller = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_transfer_from(&caller, &from, to, collectible_id)
+        }
+
+        /// Import items of a `pallet_nfts` collection, with their metadata and the attributes under `keys`. Each item keeps
+        /// its id if it fits in 16 bytes and isn't taken. The imported items are moved to the pallet account in
+        /// `pallet_nfts`, so they can't be traded on both sides. The writes of missing metadata and attributes are refunded
+        #[pallet::call_index(57)]
+        #[pallet::weight(T::WeightInfo::import_collection(items.len() as u32, keys.len() as u32))]
+        pub fn import_collection(
+            origin: OriginFor<T>,
+            collection: T::NftsCollectionId,
+            items: BoundedVec<T::NftsItemId, T::MaxDetailsQuery>,
+            keys: BoundedVec<BoundedVec<u8, T::KeyLimit>, T::MaxImportedAttributes>
+        ) -> DispatchResultWithPostInfo{
+            T::ForceOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            let charged = T::WeightInfo::import_collection(items.len() as u32, keys.len() as u32);
+            // Every item was charged the writes of its metadata and of an attribute under every key
+            let mut unwritten = (items.len() as u64).saturating_mul(keys.len() as u64 + 1);
+            for item in items{
+                let owner = T::Nfts::owner(&collection, &item).ok_or(Error::<T, I>::NoNftsItem)?;
+                let collectible_id = Self::imported_id(&collection, &item);
+                // `pallet_nfts` serves the metadata of an item as its attribute under the empty key
+                let metadata = T::Nfts::attribute(&collection, &item, &[])
+                    .map(BoundedVec::<u8, T::StringLimit>::try_from)
+                    .transpose()
+                    .map_err(|_| Error::<T, I>::MetadataTooLong)?;
+
+                Self::import(Collectible::<T, I> {
+                    unique_id: collectible_id,
+                    price: None,
+                    color: if collectible_id[0] % 2 == 0 { Color::Red } else { Color::Yellow },
+                    owner: owner.clone(),
+                    creator: owner,
+                })?;
+                if let Some(metadata) = metadata{
+                    Metadata::<T, I>::insert(collectible_id, &metadata);
+                    unwritten = unwritten.saturating_sub(1);
+                    Self::deposit_event(Event::MetadataSet { collectible: collectible_id, data: metadata });
+                }
+                for key in keys.iter(){
+                    if let Some(value) = T::Nfts::attribute(&collection, &item, key){
+                        let value = BoundedVec::<u8, T::ValueLimit>::try_from(value).map_err(|_| Error::<T, I>::AttributeTooLong)?;
+                        Attributes::<T, I>::insert(collectible_id, key, &value);
+                        unwritten = unwritten.saturating_sub(1);
+                        Self::deposit_event(Event::AttributeSet { collectible: collectible_id, key: key.clone(), value });
+                    }
+                }
+                T::Nfts::transfer(&collection, &item, &Self::account_id())?;
+
+                Self::deposit_event(Event::NftImported { collectible: collectible_id, collection: collection.clone(), item });
+            }
+            Ok(Some(charged.saturating_sub(T::DbWeight::get().writes(unwritten))).into())
+        }
+
+        /// Lock a collectible and transfer it to `to` at block `at`, e.g. for escrowed delivery dates. Transfer taxes and
+        /// royalties are charged now. The owner can cancel it until then
+        #[pallet::call_index(58)]
+        #[pallet::weight(T::WeightInfo::schedule_transfer())]
+        pub fn schedule_transfer(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            to: T::AccountId,
+            at: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == owner, Error::<T, I>::NotOwner);
+            ensure!(owner != to, Error::<T, I>::TransferToSelf);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T, I>::ScheduleInPast);
+
+            Self::charge_transfer_tax(&collectible, &owner)?;
+            Self::enforce_royalty(collectible_id, &owner, &to)?;
+            Self::lock_for(collectible_id, LockReason::ScheduledTransfer)?;
+            ScheduledTransferQueue::<T, I>::try_append(at, collectible_id).map_err(|_| Error::<T, I>::TooManyScheduledTransfers)?;
+            ScheduledTransfers::<T, I>::insert(collectible_id, (to.clone(), at));
+
+            Self::deposit_event(Event::TransferScheduled { collectible: collectible_id, to, at });
+            Ok(())
+        }
+
+        /// Cancel the scheduled transfer of a collectible, unlocking it. Taxes and royalties already charged aren't refunded
+        #[pallet::call_index(59)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_transfer())]
+        pub fn cancel_scheduled_transfer(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            let (_, at) = ScheduledTransfers::<T, I>::take(collectible_id).ok_or(Error::<T, I>::NotScheduled)?;
+            ScheduledTransferQueue::<T, I>::mutate(at, |queued| queued.retain(|queued_id| *queued_id != collectible_id));
+            Self::unlock_for(collectible_id, LockReason::ScheduledTransfer)?;
+
+            Self::deposit_event(Event::ScheduledTransferCancelled { collectible: collectible_id });
+            Ok(())
+        }
+
+        /// Let `manager` list, delist and reprice a collectible, or remove the manager if `None`. Only the owner can
+        /// transfer it. The manager is removed when the collectible changes hands
+        #[pallet::call_index(60)]
+        #[pallet::weight(T::WeightInfo::set_manager())]
+        pub fn set_manager(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            manager: Option<T::AccountId>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            Managers::<T, I>::set(collectible_id, manager.clone());
+            Self::deposit_event(Event::ManagerSet { collectible: collectible_id, manager });
+            Ok(())
+        }
+
+        /// Share a collectible with co-owners, moving it to its co-ownership account. Sale proceeds are split among them
+        /// by their shares, and transferring it away needs the approval of co-owners holding more than `threshold` of
+        /// the shares. The owner funds the deposits the account holds for the collectible, which are split among the
+        /// co-owners once it leaves
+        #[pallet::call_index(61)]
+        #[pallet::weight(T::WeightInfo::share_ownership())]
+        pub fn share_ownership(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            owners: BoundedVec<(T::AccountId, u32), T::MaxCoOwners>,
+            threshold: Perbill
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            ensure!(!CoOwned::<T, I>::contains_key(&collectible_id), Error::<T, I>::AlreadyCoOwned);
+            ensure!(!owners.is_empty(), Error::<T, I>::InvalidCoOwners);
+            for (index, (co_owner, shares)) in owners.iter().enumerate(){
+                ensure!(*shares > 0, Error::<T, I>::InvalidCoOwners);
+                ensure!(owners.iter().skip(index + 1).all(|(other, _)| other != co_owner), Error::<T, I>::InvalidCoOwners);
+            }
+            owners.iter().try_fold(0u32, |total, (_, shares)| total.checked_add(*shares)).ok_or(Error::<T, I>::InvalidCoOwners)?;
+
+            // The co-ownership account holds the deposits of the collectible from now on, funded by the owner
+            let account = Self::co_ownership_account(collectible_id);
+            let deposits = Self::deposits_of(collectible_id);
+            if !deposits.is_zero(){
+                T::Currency::transfer(
+                    &owner,
+                    &account,
+                    deposits.saturating_add(T::Currency::minimum_balance()),
+                    Preservation::Preserve
+                )?;
+            }
+            Self::do_transfer(collectible_id, account)?;
+            CoOwned::<T, I>::insert(collectible_id, CoOwnership { owners: owners.clone(), threshold });
+
+            Self::deposit_event(Event::OwnershipShared { collectible: collectible_id, owners, threshold });
+            Ok(())
+        }
+
+        /// Let a listing be bought only until block `until`, at most `MaxListingDuration` away. It's delisted afterwards,
+        /// and listing the collectible again at a new price resets the expiry to `MaxListingDuration`
+        #[pallet::call_index(63)]
+        #[pallet::weight(T::WeightInfo::set_listing_expiry())]
+        pub fn set_listing_expiry(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            until: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(until >= now, Error::<T, I>::ListingExpired);
+            ensure!(until <= now.saturating_add(T::MaxListingDuration::get()), Error::<T, I>::ListingTooLong);
+            Self::expire_listing_after(collectible_id, until);
+            Self::deposit_event(Event::ListingExpirySet { collectible: collectible_id, until });
+            Ok(())
+        }
 
-    #[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    #[scale_info(skip_type_params(T))]
-    pub struct Collectible<T: Config> {
-        // Unsigned integers of 16 bytes to represent a unique identifier
-        pub unique_id: [u8; 16],
-        // `None` assumes not for sale
-        pub price: Option<BalanceOf<T>>,
-        pub color: Color,
-        pub owner: T::AccountId,
-    }
+        /// Switch a listing to `price` from block `at` on, e.g. for timed discounts, without being online then. Listing the
+        /// collectible again drops the scheduled price
+        #[pallet::call_index(71)]
+        #[pallet::weight(T::WeightInfo::set_price_at())]
+        pub fn set_price_at(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            price: BalanceOf<T, I>,
+            at: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T, I>::ScheduleInPast);
+            ScheduledPrices::<T, I>::insert(collectible_id, (price, at));
+            Self::deposit_event(Event::PriceScheduled { collectible: collectible_id, price, at });
+            Ok(())
+        }
 
-    #[pallet::storage]
-    pub(super) type CollectiblesCount<T:Config> = StorageValue<_,u64,ValueQuery>;
+        /// List a collectible at `price`, dropping by `decrement` every block until it sells or reaches `floor`. Listing
+        /// it again drops the decline
+        #[pallet::call_index(72)]
+        #[pallet::weight(T::WeightInfo::set_declining_price())]
+        pub fn set_declining_price(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            price: BalanceOf<T, I>,
+            decrement: BalanceOf<T, I>,
+            floor: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            Self::ensure_manages(&collectible, &from)?;
+            Self::ensure_relist_allowed(&collectible)?;
+            ensure!(floor <= price, Error::<T, I>::FloorAbovePrice);
+            collectible.price = Some(price);
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            DecliningPrices::<T, I>::insert(
+                collectible_id,
+                (decrement, floor, frame_system::Pallet::<T>::block_number())
+            );
+            Self::cap_listing_duration(collectible_id);
+            Self::touch(collectible_id);
+            Self::deposit_event(Event::DecliningPriceSet { collectible: collectible_id, price, decrement, floor });
+            Ok(())
+        }
 
-    /// Maps the Collectible struct to the unique_id.
-    #[pallet::storage]
-    pub(super) type CollectibleMap<T: Config> = StorageMap<_, Twox64Concat, [u8; 16], Collectible<T>>;
+        /// Opt the collectibles the caller created into a relist cooldown: they can't be listed for `cooldown` blocks
+        /// after changing hands. `None` lifts it. Only applies to transfers made while it's set
+        #[pallet::call_index(73)]
+        #[pallet::weight(T::WeightInfo::set_relist_cooldown())]
+        pub fn set_relist_cooldown(
+            origin: OriginFor<T>,
+            cooldown: Option<BlockNumberFor<T>>
+        ) -> DispatchResult{
+            let creator = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            RelistCooldowns::<T, I>::set(&creator, cooldown);
+            Self::deposit_event(Event::RelistCooldownSet { creator, cooldown });
+            Ok(())
+        }
 
-    /// Track the collectibles owned by each account.
-    #[pallet::storage]
-    pub(super) type OwnerOfCollectibles<T: Config> = StorageMap<
-        _,
-        Twox64Concat,
-        T::AccountId,
-        BoundedVec<[u8; 16], T::MaximumOwned>,
-        ValueQuery,
-    >;
+        /// Write a call option on a collectible: whoever buys it for `premium` can buy the collectible at `strike` until
+        /// block `expiry`. The collectible stays locked until the option is exercised or closed
+        #[pallet::call_index(74)]
+        #[pallet::weight(T::WeightInfo::write_option())]
+        pub fn write_option(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            premium: BalanceOf<T, I>,
+            strike: BalanceOf<T, I>,
+            expiry: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let writer = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == writer, Error::<T, I>::NotOwner);
+            ensure!(collectible.price.is_none(), Error::<T, I>::CollectibleOnSale);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T, I>::ScheduleInPast);
+            Self::lock_for(collectible_id, LockReason::CallOption)?;
+            CallOptions::<T, I>::insert(
+                collectible_id,
+                CallOption { writer: writer.clone(), premium, strike, expiry, holder: None }
+            );
+            Self::deposit_event(Event::OptionWritten { collectible: collectible_id, writer, premium, strike, expiry });
+            Ok(())
+        }
 
+        /// Buy a call option, paying its premium to the writer
+        #[pallet::call_index(75)]
+        #[pallet::weight(T::WeightInfo::buy_option())]
+        pub fn buy_option(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let holder = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut option = CallOptions::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoOption)?;
+            ensure!(option.holder.is_none(), Error::<T, I>::OptionTaken);
+            ensure!(frame_system::Pallet::<T>::block_number() <= option.expiry, Error::<T, I>::OptionExpired);
+            T::Currency::transfer(&holder, &option.writer, option.premium, Preservation::Preserve)?;
+            option.holder = Some(holder.clone());
+            CallOptions::<T, I>::insert(collectible_id, option);
+            Self::deposit_event(Event::OptionBought { collectible: collectible_id, holder });
+            Ok(())
+        }
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Each collectible must have a unique identifier
-        DuplicateCollectible,
-        /// An account can't exceed the `MaximumOwned` constant
-        MaximumCollectiblesOwned,
-        /// The total supply of collectibles can't exceed the u64 limit
-        BoundsOverflow,
-        /// The collectible doesn't exist
-        NoCollectible,
-        /// You are not the owner
-        NotOwner,
-        /// Trying to transfer a collectible to yourself
-        TransferToSelf,
-        /// Error sent when trying to buy or get/remove the price of a collectible which's not on sale
-        CollectibleNotForSale,
-        /// Error sent if trying to buy a collectible under its price
-        OfferedPriceTooLow
-    }
+        /// Exercise a call option, buying the collectible at the strike price as a sale paying the usual royalty,
+        /// charity and burn
+        #[pallet::call_index(76)]
+        #[pallet::weight(T::WeightInfo::exercise_option())]
+        pub fn exercise_option(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let holder = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let option = CallOptions::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoOption)?;
+            ensure!(option.holder.as_ref() == Some(&holder), Error::<T, I>::NotOptionHolder);
+            ensure!(frame_system::Pallet::<T>::block_number() <= option.expiry, Error::<T, I>::OptionExpired);
+            CallOptions::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::CallOption)?;
+            // The sale was committed when the option was bought, so pausing trading doesn't stop it
+            TradingPaused::<T, I>::remove(collectible_id);
+            Self::do_buy_in(collectible_id, holder.clone(), None, option.strike, Preservation::Preserve)?;
+            Self::deposit_event(Event::OptionExercised { collectible: collectible_id, holder });
+            Ok(())
+        }
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// A new collectible was successfully created
-        CollectibleCreated { collectible: [u8; 16], owner: T::AccountId },
-        /// A collectible was successfully transferred.
-        TransferSucceeded { from: T::AccountId, to: T::AccountId, collectible: [u8; 16] },
-        /// A collectible's owner has set a price for it
-        PriceSet { collectible: [u8;16], price: BalanceOf<T> },
-        /// A collectible's owner has retired it from the market
-        NotLongerOnSale { collectible: [u8;16] },
-        /// A purchase occured
-        Sold { seller: T::AccountId, buyer: T::AccountId, collectible: [u8;16], price: BalanceOf<T>},
-        /// A collectible's been destroyed
-        CollectibleDestroyed { collectible: [u8;16] }
-    }
+        /// Close a call option nobody bought, or that expired, unlocking the collectible
+        #[pallet::call_index(77)]
+        #[pallet::weight(T::WeightInfo::close_option())]
+        pub fn close_option(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let writer = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let option = CallOptions::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoOption)?;
+            ensure!(option.writer == writer, Error::<T, I>::NotOptionWriter);
+            ensure!(
+                option.holder.is_none() || frame_system::Pallet::<T>::block_number() > option.expiry,
+                Error::<T, I>::OptionActive
+            );
+            CallOptions::<T, I>::remove(collectible_id);
+            Self::unlock_for(collectible_id, LockReason::CallOption)?;
+            Self::deposit_event(Event::OptionClosed { collectible: collectible_id });
+            Ok(())
+        }
 
+        /// Buy a collectible listed in the native currency with the price held in escrow: the collectible is delivered
+        /// locked, and the buyer can dispute the sale for `EscrowPeriod` blocks, e.g. if an off-chain deliverable tied
+        /// to it never arrives. The price is released to the seller afterwards
+        #[pallet::call_index(78)]
+        #[pallet::weight(T::WeightInfo::buy_escrowed())]
+        pub fn buy_escrowed(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            offered_price: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let buyer = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            ensure!(!ListingAssets::<T, I>::contains_key(&collectible_id), Error::<T, I>::WrongPaymentAsset);
+            let price = Self::quote(&collectible).ok_or(Error::<T, I>::OracleUnavailable)?;
+            ensure!(offered_price >= price, Error::<T, I>::OfferedPriceTooLow);
+            Self::ensure_not_touched(collectible_id)?;
+            Self::ensure_can_buy(collectible_id, &buyer)?;
+            let co_ownership = CoOwned::<T, I>::get(&collectible_id);
+            T::Currency::transfer(&buyer, &Self::escrow_account(collectible_id), offered_price, Preservation::Preserve)?;
+            Self::do_transfer(collectible_id, buyer.clone())?;
+            Self::lock_for(collectible_id, LockReason::Escrow)?;
+            let release_at = frame_system::Pallet::<T>::block_number().saturating_add(T::EscrowPeriod::get());
+            Escrows::<T, I>::insert(collectible_id, Escrow {
+                seller: collectible.owner.clone(),
+                buyer: buyer.clone(),
+                price: offered_price,
+                co_ownership,
+                release_at,
+                disputed: false,
+            });
+            Self::deposit_event(Event::EscrowOpened {
+                collectible: collectible_id,
+                seller: collectible.owner,
+                buyer,
+                price: offered_price,
+                release_at
+            });
+            Ok(())
+        }
 
-    impl<T:Config> Pallet<T>{
-        fn gen_unique_id() -> ([u8;16], Color){
-            let random = T::Randomness::random(&b"unique_id"[..]).0;
+        /// Release the price of an undisputed escrowed sale to the seller once its dispute window closed. Anyone can
+        /// call it
+        #[pallet::call_index(79)]
+        #[pallet::weight(T::WeightInfo::release_escrow())]
+        pub fn release_escrow(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let escrow = Escrows::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoEscrow)?;
+            ensure!(!escrow.disputed, Error::<T, I>::EscrowDisputed);
+            ensure!(frame_system::Pallet::<T>::block_number() >= escrow.release_at, Error::<T, I>::EscrowNotOver);
+            Self::release_escrow_to_seller(collectible_id, escrow)
+        }
 
-            let unique_payload = (
-                random,
-                frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
-                frame_system::Pallet::<T>::block_number()
-            );
+        /// Dispute an escrowed sale within its dispute window, leaving the decision to the `ArbitratorOrigin`
+        #[pallet::call_index(80)]
+        #[pallet::weight(T::WeightInfo::dispute_escrow())]
+        pub fn dispute_escrow(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            let mut escrow = Escrows::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoEscrow)?;
+            ensure!(escrow.buyer == who, Error::<T, I>::NotBuyer);
+            ensure!(!escrow.disputed, Error::<T, I>::EscrowDisputed);
+            ensure!(frame_system::Pallet::<T>::block_number() < escrow.release_at, Error::<T, I>::EscrowOver);
+            escrow.disputed = true;
+            Escrows::<T, I>::insert(collectible_id, escrow);
+            Self::deposit_event(Event::EscrowDisputed { collectible: collectible_id });
+            Ok(())
+        }
 
-            let encode_payload = unique_payload.encode();
-            let hash = frame_support::Hashable::blake2_128(&encode_payload);
-            if hash[0]%2 == 0 {
-                (hash, Color::Red)
+        /// Decide a disputed escrowed sale: refund the buyer and give the collectible back to the seller, or release the
+        /// price to the seller
+        #[pallet::call_index(81)]
+        #[pallet::weight(T::WeightInfo::resolve_dispute())]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            refund: bool
+        ) -> DispatchResult{
+            T::ArbitratorOrigin::ensure_origin(origin)?;
+            Self::ensure_migrated()?;
+            let escrow = Escrows::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoEscrow)?;
+            ensure!(escrow.disputed, Error::<T, I>::EscrowNotDisputed);
+            if refund{
+                Escrows::<T, I>::remove(collectible_id);
+                Self::unlock_for(collectible_id, LockReason::Escrow)?;
+                T::Currency::transfer(
+                    &Self::escrow_account(collectible_id),
+                    &escrow.buyer,
+                    escrow.price,
+                    Preservation::Expendable
+                )?;
+                Self::do_transfer(collectible_id, escrow.seller)?;
+                if let Some(co_ownership) = escrow.co_ownership{
+                    CoOwned::<T, I>::insert(collectible_id, co_ownership);
+                }
             }
             else{
-                (hash, Color::Yellow)
+                Self::release_escrow_to_seller(collectible_id, escrow)?;
             }
+            Self::deposit_event(Event::DisputeResolved { collectible: collectible_id, refunded: refund });
+            Ok(())
         }
 
-        fn mint(
-            owner: &T::AccountId,
-            unique_id: [u8;16],
-            color: Color
-        ) -> Result<[u8;16],DispatchError>{
-            let collectible = Collectible::<T> {
-                unique_id,
-                price: None,
-                color,
-                owner: owner.clone()
-            };
-
-            ensure!(!CollectibleMap::<T>::contains_key(&unique_id), Error::<T>::DuplicateCollectible);
-            let count = CollectiblesCount::<T>::get();
-            let new_count = count.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
+        /// Gift a collectible to `recipient`, who can claim it until block `claim_deadline`, free of fees with the
+        /// `feeless::SkipFeelessClaims` transaction extension. It's locked until then, and reverts to the sender if left
+        /// unclaimed, so gifts to mistyped accounts aren't stranded. Transfer taxes and royalties are charged now, and
+        /// aren't refunded if the gift reverts
+        #[pallet::call_index(82)]
+        #[pallet::weight(T::WeightInfo::gift())]
+        pub fn gift(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            recipient: T::AccountId,
+            claim_deadline: BlockNumberFor<T>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == from, Error::<T, I>::NotOwner);
+            ensure!(from != recipient, Error::<T, I>::TransferToSelf);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            ensure!(claim_deadline >= frame_system::Pallet::<T>::block_number(), Error::<T, I>::ScheduleInPast);
+            Self::charge_transfer_tax(&collectible, &from)?;
+            Self::enforce_royalty(collectible_id, &from, &recipient)?;
+            Self::lock_for(collectible_id, LockReason::Gift)?;
+            GiftExpiryQueue::<T, I>::try_append(claim_deadline.saturating_add(One::one()), collectible_id)
+                .map_err(|_| Error::<T, I>::TooManyGiftExpiries)?;
+            Gifts::<T, I>::insert(collectible_id, (from.clone(), recipient.clone(), claim_deadline));
+            Self::deposit_event(Event::Gifted { collectible: collectible_id, from, recipient, claim_deadline });
+            Ok(())
+        }
 
-            OwnerOfCollectibles::<T>::try_append(&owner, unique_id)
-                .map_err(|_| Error::<T>::MaximumCollectiblesOwned)?;
+        /// Claim a collectible gifted to the caller
+        #[pallet::call_index(83)]
+        #[pallet::weight(T::WeightInfo::claim_gift())]
+        pub fn claim_gift(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (_, recipient, deadline) = Gifts::<T, I>::take(collectible_id).ok_or(Error::<T, I>::NoGift)?;
+            ensure!(recipient == who, Error::<T, I>::NotRecipient);
+            GiftExpiryQueue::<T, I>::mutate(deadline.saturating_add(One::one()), |queued| {
+                queued.retain(|queued_id| *queued_id != collectible_id)
+            });
+            Self::unlock_for(collectible_id, LockReason::Gift)?;
+            Self::do_transfer(collectible_id, who.clone())?;
+            Self::deposit_event(Event::GiftClaimed { collectible: collectible_id, recipient: who });
+            Ok(())
+        }
 
-            CollectibleMap::<T>::insert(unique_id, collectible);
-            CollectiblesCount::<T>::put(new_count);
+        /// Tip the creator side of a collectible outside of sales: its royalty beneficiary if it has a royalty, its
+        /// creator otherwise
+        #[pallet::call_index(84)]
+        #[pallet::weight(T::WeightInfo::tip_creator())]
+        pub fn tip_creator(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            amount: BalanceOf<T, I>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            let to = Self::royalty_of(collectible_id)
+                .map_or(collectible.creator, |royalty| royalty.beneficiary);
+            T::Currency::transfer(&from, &to, amount, Preservation::Preserve)?;
+            Self::deposit_event(Event::CreatorTipped { collectible: collectible_id, from, to, amount });
+            Ok(())
+        }
 
-            Self::deposit_event(Event::CollectibleCreated { collectible: unique_id, owner: owner.clone() });
+        /// Name a collectible, holding `NameDeposit` from the owner unless it's already named. If `UniqueNames`, the name
+        /// can't be held by another collectible. Depending on `KeepNamesOnTransfer`, the name is cleared when the
+        /// collectible changes hands or kept, with the new owner holding the deposit in turn
+        #[pallet::call_index(85)]
+        #[pallet::weight(T::WeightInfo::set_name())]
+        pub fn set_name(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            name: BoundedVec<u8, T::NameLimit>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            if T::UniqueNames::get(){
+                ensure!(
+                    NamedCollectibles::<T, I>::get(&name).map_or(true, |named| named == collectible_id),
+                    Error::<T, I>::NameTaken
+                );
+            }
+            let deposit = match Names::<T, I>::get(&collectible_id){
+                Some((old_name, deposit)) => {
+                    NamedCollectibles::<T, I>::remove(&old_name);
+                    deposit
+                },
+                None => {
+                    let deposit = NameDeposit::<T, I>::get();
+                    T::Currency::hold(&HoldReason::NameDeposit.into(), &owner, deposit)?;
+                    deposit
+                },
+            };
+            if T::UniqueNames::get(){
+                NamedCollectibles::<T, I>::insert(&name, collectible_id);
+            }
+            Names::<T, I>::insert(collectible_id, (name.clone(), deposit));
+            Self::deposit_event(Event::NameSet { collectible: collectible_id, name });
+            Ok(())
+        }
 
-            Ok(unique_id)
+        /// Clear the name of a collectible, releasing its deposit
+        #[pallet::call_index(86)]
+        #[pallet::weight(T::WeightInfo::clear_name())]
+        pub fn clear_name(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            ensure!(Names::<T, I>::contains_key(&collectible_id), Error::<T, I>::NoName);
+            Self::remove_name(collectible_id, &owner)
         }
 
-        // Update storage to transfer collectible
-        pub fn do_transfer(
+        /// Set the caller's note on a collectible they own, holding `NoteDeposit` unless they already keep one. `None`
+        /// clears it, releasing the deposit. Notes are cleared when the collectible changes hands
+        #[pallet::call_index(87)]
+        #[pallet::weight(T::WeightInfo::set_note())]
+        pub fn set_note(
+            origin: OriginFor<T>,
             collectible_id: [u8; 16],
-            to: T::AccountId,
-        ) -> DispatchResult {
-            let (collectible, from, from_collection, to_collection) = Self::pre_transfer(collectible_id, &to)?;
-            Self::post_transfer(&collectible, &from, &to, from_collection, to_collection);		
-            Self::deposit_event(Event::TransferSucceeded { from, to, collectible: collectible_id });
+            note: Option<BoundedVec<u8, T::NoteLimit>>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            let Some(note) = note else {
+                ensure!(Notes::<T, I>::contains_key(&owner, collectible_id), Error::<T, I>::NoNote);
+                return Self::remove_note(collectible_id, &owner);
+            };
+            let deposit = match Notes::<T, I>::get(&owner, collectible_id){
+                Some((_, deposit)) => deposit,
+                None => {
+                    let deposit = NoteDeposit::<T, I>::get();
+                    T::Currency::hold(&HoldReason::NoteDeposit.into(), &owner, deposit)?;
+                    deposit
+                },
+            };
+            Notes::<T, I>::insert(&owner, collectible_id, (note.clone(), deposit));
+            Self::deposit_event(Event::NoteSet { collectible: collectible_id, owner, note });
             Ok(())
         }
 
-        pub fn do_buy(
+        /// Pause trading a collectible, so it can't be bought even if listed, or resume it. Unlike locks, the owner keeps
+        /// using the collectible as usual. Locked collectibles can't be paused, as they may be committed to a sale, and
+        /// call options are exercised even if trading was paused before writing them
+        #[pallet::call_index(88)]
+        #[pallet::weight(T::WeightInfo::set_trading_paused())]
+        pub fn set_trading_paused(
+            origin: OriginFor<T>,
             collectible_id: [u8; 16],
-            buyer: T::AccountId,
-            price: BalanceOf<T>
+            paused: bool
         ) -> DispatchResult{
-            let (collectible, seller, seller_collection, buyer_collection) = Self::pre_transfer(collectible_id, &buyer)?;
-            // Nothing can fail after the balance transfer, so this is the latest point where we can return an error. After that, it's enoguh with updating the storage
-            T::Currency::transfer(&buyer, &seller, price, frame_support::traits::tokens::ExistenceRequirement::KeepAlive)?;
-            // Update storage
-            Self::post_transfer(&collectible, &seller, &buyer, seller_collection, buyer_collection);
-            Self::deposit_event(Event::Sold{ seller, buyer , collectible: collectible_id, price});      
+            let owner = ensure_signed(origin)?;
+            Self::ensure_migrated()?;
+            Self::ensure_owner(collectible_id, &owner)?;
+            if paused{
+                ensure!(!LockedCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleLocked);
+                TradingPaused::<T, I>::insert(collectible_id, ());
+            }
+            else{
+                TradingPaused::<T, I>::remove(collectible_id);
+            }
+            Self::deposit_event(Event::TradingPauseSet { collectible: collectible_id, paused });
             Ok(())
         }
 
-        /// This function encapsulates all the logic needed before a transfer/purchase
-        fn pre_transfer(
+        /// Require buyers of a listing to meet `condition`, as checked by the runtime's `BuyerQualification`, or lift the
+        /// requirement with `None`. Listing the collectible again drops the condition
+        #[pallet::call_index(89)]
+        #[pallet::weight(T::WeightInfo::set_listing_condition())]
+        pub fn set_listing_condition(
+            origin: OriginFor<T>,
             collectible_id: [u8; 16],
-            to: &T::AccountId
-        ) -> Result<
-            (
-                Collectible<T>, 
-                T::AccountId, 
-                BoundedVec<[u8; 16], T::MaximumOwned>, 
-                BoundedVec<[u8; 16], T::MaximumOwned>
-            ), Error<T>
-        >{
-            let mut collectible = CollectibleMap::<T>::get(&collectible_id).unwrap(); // Collectible exists is already checked in the callable functions
-            let from = collectible.owner;
-            // Ensure the collectible isn't sent to its owner
-            ensure!(from != *to, Error::<T>::TransferToSelf);
-            // Retrieve the collection of 'from' and 'to'
-            let mut from_collection = OwnerOfCollectibles::<T>::get(&from);
-            let mut  to_collection = OwnerOfCollectibles::<T>::get(&to);
-
-            // Remove the collectible from the 'from' collection
-            if let Some(index) = from_collection.iter().position(|&element| element == collectible_id){
-                from_collection.swap_remove(index);
-            } // Cannot be None if everything is well implemented, as we know this account owns the collectible due to the previous lines
-
-            // Try pushing the collectible into the to collection
-            to_collection.try_push(collectible_id).map_err(|_| Error::<T>::MaximumCollectiblesOwned)?;
-
-            collectible.owner = to.clone();
-            collectible.price = None; // After transfer, the token isn't in sale, its new owner must set the desired price if wishing to sell it
+            condition: Option<<T::BuyerQualification as BuyerQualification<T::AccountId>>::Condition>
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            Self::ensure_manages(&collectible, &from)?;
+            ensure!(collectible.price.is_some(), Error::<T, I>::CollectibleNotForSale);
+            ListingConditions::<T, I>::set(collectible_id, condition.clone());
+            Self::deposit_event(Event::ListingConditionSet { collectible: collectible_id, condition });
+            Ok(())
+        }
 
-            Ok((collectible, from, from_collection, to_collection))
+        /// Allow `who` to hold a collectible the caller created. The first allowed holder restricts the collectible: from
+        /// then on it can only be transferred to its allowed holders
+        #[pallet::call_index(90)]
+        #[pallet::weight(T::WeightInfo::allow_holder())]
+        pub fn allow_holder(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            who: T::AccountId
+        ) -> DispatchResult{
+            let creator = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.creator == creator, Error::<T, I>::NotCreator);
+            AllowedHolders::<T, I>::try_mutate(collectible_id, |allowed| -> DispatchResult{
+                let allowed = allowed.get_or_insert_with(Default::default);
+                if !allowed.contains(&who){
+                    allowed.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyAllowedHolders)?;
+                }
+                Ok(())
+            })?;
+            Self::deposit_event(Event::HolderAllowed { collectible: collectible_id, who });
+            Ok(())
         }
 
-        // This function updates storage after every transfer/purchase
-        fn post_transfer(
-            collectible: &Collectible<T>,
-            from: &T::AccountId,
-            to: &T::AccountId,
-            from_collection: BoundedVec<[u8; 16], T::MaximumOwned>,
-            to_collection: BoundedVec<[u8; 16], T::MaximumOwned>
-        ){
-            // Write updates to storage
-            CollectibleMap::<T>::insert(collectible.unique_id, collectible);
-            OwnerOfCollectibles::<T>::insert(from, from_collection);
-            OwnerOfCollectibles::<T>::insert(to, to_collection);
+        /// Remove `who` from the allowed holders of a collectible the caller created. The collectible stays restricted,
+        /// even once nobody is allowed. Its current owner keeps it
+        #[pallet::call_index(91)]
+        #[pallet::weight(T::WeightInfo::disallow_holder())]
+        pub fn disallow_holder(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            who: T::AccountId
+        ) -> DispatchResult{
+            let creator = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.creator == creator, Error::<T, I>::NotCreator);
+            AllowedHolders::<T, I>::mutate(collectible_id, |allowed| {
+                if let Some(allowed) = allowed{
+                    allowed.retain(|holder| *holder != who);
+                }
+            });
+            Self::deposit_event(Event::HolderDisallowed { collectible: collectible_id, who });
+            Ok(())
         }
-    }
 
-    #[pallet::call]
-    impl<T:Config> Pallet<T>{
-        #[pallet::weight(0)]
-        pub fn create_collectible(origin: OriginFor<T>) -> DispatchResult{
-            let sender = ensure_signed(origin)?;
+        /// Set how many more collectibles the pallet `minter` can mint through `RewardCollectible`
+        #[pallet::call_index(92)]
+        #[pallet::weight(T::WeightInfo::set_reward_quota())]
+        pub fn set_reward_quota(
+            origin: OriginFor<T>,
+            minter: PalletId,
+            quota: u32
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            RewardQuotas::<T, I>::insert(minter, quota);
+            Self::deposit_event(Event::RewardQuotaSet { minter, quota });
+            Ok(())
+        }
 
-            let (unique_id, color) = Self::gen_unique_id();
+        /// Record who holds every collectible at this block as the root of a Merkle tree, which `verify_holder` checks
+        /// claims against later. Fails if there are more than `MaxSnapshotHolders` collectibles
+        #[pallet::call_index(93)]
+        #[pallet::weight(T::WeightInfo::snapshot_holders(T::MaxSnapshotHolders::get()))]
+        pub fn snapshot_holders(origin: OriginFor<T>) -> DispatchResultWithPostInfo{
+            ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectibles = CollectibleMap::<T, I>::count();
+            ensure!(collectibles <= T::MaxSnapshotHolders::get(), Error::<T, I>::TooManyToSnapshot);
+            let mut level: Vec<T::Hash> = CollectibleMap::<T, I>::iter()
+                .map(|(collectible_id, collectible)| T::Hashing::hash_of(&(collectible_id, collectible.owner)))
+                .collect();
+            while level.len() > 1{
+                level = level
+                    .chunks(2)
+                    .map(|pair| match pair{
+                        [a, b] => Self::hash_pair(a, b),
+                        _ => pair[0],
+                    })
+                    .collect();
+            }
+            let root = level.first().copied().unwrap_or_default();
+            let at = frame_system::Pallet::<T>::block_number();
+            let snapshot = NextSnapshotId::<T, I>::get();
+            NextSnapshotId::<T, I>::put(snapshot.checked_add(1).ok_or(Error::<T, I>::BoundsOverflow)?);
+            HolderSnapshots::<T, I>::insert(snapshot, HolderSnapshot { at, root, collectibles });
+            Self::deposit_event(Event::HoldersSnapshotted { snapshot, at, root });
+            Ok(Some(T::WeightInfo::snapshot_holders(collectibles)).into())
+        }
 
-            Self::mint(&sender, unique_id, color)?;
+        /// Set the royalty of the caller's collectibles without one of their own, or remove it if `None`. It must be
+        /// within the range governance allows, and applies to the collectibles the caller created, now or later
+        #[pallet::call_index(94)]
+        #[pallet::weight(T::WeightInfo::set_default_royalty())]
+        pub fn set_default_royalty(
+            origin: OriginFor<T>,
+            royalty: Option<Royalty<T::AccountId>>
+        ) -> DispatchResult{
+            let creator = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_royalty_allowed(&royalty)?;
+            DefaultRoyalties::<T, I>::set(&creator, royalty.clone());
+            Self::deposit_event(Event::DefaultRoyaltySet { creator, royalty });
+            Ok(())
+        }
 
+        /// Set the range of the rates creators can set their collectibles' royalties at, or remove it if `None`. It
+        /// can't exceed `MaxRoyalty`. Royalties set before aren't affected
+        #[pallet::call_index(95)]
+        #[pallet::weight(T::WeightInfo::set_royalty_range())]
+        pub fn set_royalty_range(
+            origin: OriginFor<T>,
+            range: Option<(Permill, Permill)>
+        ) -> DispatchResult{
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                range.map_or(true, |(lowest, highest)| lowest <= highest && highest <= T::MaxRoyalty::get()),
+                Error::<T, I>::InvalidRoyaltyRange
+            );
+            RoyaltyRange::<T, I>::set(range);
+            Self::deposit_event(Event::RoyaltyRangeSet { range });
             Ok(())
         }
 
-        #[pallet::weight(0)] 
-        pub fn destroy_collectible(
+        /// Settle an ended buyout. Submitted unsigned by the offchain worker
+        #[pallet::call_index(65)]
+        #[pallet::weight(T::WeightInfo::settle_buyout())]
+        pub fn settle_buyout_unsigned(
             origin: OriginFor<T>,
             collectible_id: [u8; 16]
         ) -> DispatchResult{
-            let sender = ensure_signed(origin)?;
-
-            let collectible = CollectibleMap::<T>::get(&collectible_id).ok_or(Error::<T>::NoCollectible)?;
-            ensure!(collectible.owner == sender, Error::<T>::NotOwner);
+            ensure_none(origin)?;
+            Self::ensure_not_paused()?;
+            Self::do_settle_buyout(collectible_id)
+        }
 
-            let count = CollectiblesCount::<T>::get();
-            CollectiblesCount::<T>::put(count-1); // No risk of underflow as this collectible indeed exists, so count is at least 1.
+        /// Hand out a sample collectible to `who` on test networks, holding no deposit from them. Submitted unsigned, as
+        /// `who` may have no funds yet, and rate limited by `FaucetCooldown` and `MaxFaucetClaimsPerBlock`
+        #[pallet::call_index(66)]
+        #[pallet::weight(T::WeightInfo::claim_dev_collectible())]
+        pub fn claim_dev_collectible(
+            origin: OriginFor<T>,
+            who: T::AccountId
+        ) -> DispatchResult{
+            ensure_none(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_faucet_claimable(&who)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            LastFaucetClaim::<T, I>::insert(&who, now);
+            FaucetClaims::<T, I>::mutate(|(block, claims)| {
+                if *block != now{
+                    *block = now;
+                    *claims = 0;
+                }
+                *claims = claims.saturating_add(1);
+            });
 
-            // Remove the collectible from the map
-            CollectibleMap::<T>::remove(&collectible_id);
+            let (unique_id, color) = Self::gen_unique_id();
+            Self::import(Collectible::<T, I> { unique_id, price: None, color, owner: who.clone(), creator: who.clone() })?;
+            Self::deposit_event(Event::CollectibleCreated {
+                collectible: unique_id,
+                owner: who.clone(),
+                color,
+                verified: VerifiedCreators::<T, I>::contains_key(&who)
+            });
+            Ok(())
+        }
 
-            // Remove the collectible from the 'sender' collection
-            let mut sender_collection = OwnerOfCollectibles::<T>::get(&sender);
-            if let Some(index) = sender_collection.iter().position(|&element| element == collectible_id){
-                sender_collection.swap_remove(index);
-            } // Cannot be None if everything is well implemented, as we know this account owns the collectible due to the previous lines
-            OwnerOfCollectibles::<T>::insert(sender, sender_collection);
+        /// Airdrop a collectible to `recipient`, locking it until they claim it. Claiming it is free of fees with the
+        /// `feeless::SkipFeelessClaims` transaction extension. The sender pays the transfer tax and royalties now, and they
+        /// aren't refunded if the airdrop is cancelled
+        #[pallet::call_index(67)]
+        #[pallet::weight(T::WeightInfo::airdrop())]
+        pub fn airdrop(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16],
+            recipient: T::AccountId
+        ) -> DispatchResult{
+            let from = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == from, Error::<T, I>::NotOwner);
+            ensure!(from != recipient, Error::<T, I>::TransferToSelf);
+            ensure!(!StolenCollectibles::<T, I>::contains_key(&collectible_id), Error::<T, I>::CollectibleStolen);
+            // The recipient may have no funds yet, so the sender pays
+            Self::charge_transfer_tax(&collectible, &from)?;
+            Self::enforce_royalty_paid_by(collectible_id, &from, &recipient, &from)?;
+            Self::lock_for(collectible_id, LockReason::Airdrop)?;
+            Airdrops::<T, I>::insert(collectible_id, (from.clone(), recipient.clone()));
+            Self::deposit_event(Event::Airdropped { collectible: collectible_id, from, recipient });
+            Ok(())
+        }
 
-            Self::deposit_event(Event::CollectibleDestroyed { collectible: collectible_id });
+        /// Claim a collectible airdropped to the caller
+        #[pallet::call_index(68)]
+        #[pallet::weight(T::WeightInfo::claim_airdrop())]
+        pub fn claim_airdrop(
+            origin: OriginFor<T>,
+            collectible_id: [u8; 16]
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (_, recipient) = Airdrops::<T, I>::take(collectible_id).ok_or(Error::<T, I>::NoAirdrop)?;
+            ensure!(recipient == who, Error::<T, I>::NotRecipient);
+            Self::unlock_for(collectible_id, LockReason::Airdrop)?;
+            Self::do_transfer(collectible_id, who.clone())?;
+            Self::deposit_event(Event::AirdropClaimed { collectible: collectible_id, recipient: who });
             Ok(())
         }
 
-        /// Transfer a collectible to another account.
-        /// Any account that holds a collectible can send it to another account. 
-        /// Transfer resets the price of the collectible, marking it not for sale.
-        #[pallet::weight(0)]
-        pub fn transfer(
+        /// Cancel an airdrop that hasn't been claimed yet, unlocking the collectible
+        #[pallet::call_index(69)]
+        #[pallet::weight(T::WeightInfo::cancel_airdrop())]
+        pub fn cancel_airdrop(
             origin: OriginFor<T>,
-            to: T::AccountId,
             collectible_id: [u8; 16]
-        ) -> DispatchResult {
-            // Make sure the caller is from a signed origin
-            let from = ensure_signed(origin)?;
-            let collectible = CollectibleMap::<T>::get(&collectible_id).ok_or(Error::<T>::NoCollectible)?;
-            ensure!(collectible.owner == from, Error::<T>::NotOwner);
-            Self::do_transfer(collectible_id, to)?;
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let (from, _) = Airdrops::<T, I>::take(collectible_id).ok_or(Error::<T, I>::NoAirdrop)?;
+            ensure!(from == who, Error::<T, I>::NotSender);
+            Self::unlock_for(collectible_id, LockReason::Airdrop)?;
+            Self::deposit_event(Event::AirdropCancelled { collectible: collectible_id });
             Ok(())
         }
 
-        #[pallet::weight(0)]
-        pub fn set_price(
+        /// Submit a transfer pre-signed by the collectible's owner, `signer`, over its `pre_signed_payload`. The caller
+        /// sponsors it: they pay the fees, and the transfer tax and minimum royalty if any, so the owner needs no funds
+        #[pallet::call_index(70)]
+        #[pallet::weight(T::WeightInfo::transfer_pre_signed())]
+        pub fn transfer_pre_signed(
             origin: OriginFor<T>,
-            collectible_id: [u8; 16],
-            new_price: BalanceOf<T>
+            transfer: PreSignedTransfer<T::AccountId, BlockNumberFor<T>>,
+            signature: T::OffchainSignature,
+            signer: T::AccountId
         ) -> DispatchResult{
-            let from = ensure_signed(origin)?;
-            let mut collectible = CollectibleMap::<T>::get(&collectible_id).ok_or(Error::<T>::NoCollectible)?;
-            ensure!(collectible.owner == from, Error::<T>::NotOwner);
-            collectible.price = Some(new_price);
-            CollectibleMap::<T>::insert(collectible_id, collectible);
-            Self::deposit_event(Event::PriceSet { collectible: collectible_id, price: new_price });
+            let sponsor = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(
+                transfer.deadline >= frame_system::Pallet::<T>::block_number(),
+                Error::<T, I>::DeadlinePassed
+            );
+            ensure!(signature.verify(&Self::pre_signed_payload(&transfer)[..], &signer), Error::<T, I>::WrongSignature);
+            ensure!(transfer.nonce == TransferNonces::<T, I>::get(&signer), Error::<T, I>::WrongNonce);
+            let PreSignedTransfer { collectible: collectible_id, to, .. } = transfer;
+            let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+            ensure!(collectible.owner == signer, Error::<T, I>::NotOwner);
+            TransferNonces::<T, I>::mutate(&signer, |nonce| *nonce = nonce.saturating_add(1));
+            Self::charge_transfer_tax(&collectible, &sponsor)?;
+            Self::enforce_royalty_paid_by(collectible_id, &signer, &to, &sponsor)?;
+            Self::do_transfer(collectible_id, to)?;
+            Self::deposit_event(Event::SponsoredTransfer { collectible: collectible_id, sponsor });
             Ok(())
         }
 
-        #[pallet::weight(0)] 
-        pub fn remove_from_market(
+        /// Delist an expired listing. Submitted unsigned by the offchain worker
+        #[pallet::call_index(64)]
+        #[pallet::weight(T::WeightInfo::delist_expired())]
+        pub fn delist_expired(
             origin: OriginFor<T>,
             collectible_id: [u8; 16]
         ) -> DispatchResult{
-            let from = ensure_signed(origin)?;
-            let mut collectible = CollectibleMap::<T>::get(&collectible_id).ok_or(Error::<T>::NoCollectible)?;
-            ensure!(collectible.owner == from, Error::<T>::NotOwner);
-            ensure!(collectible.price.is_some(), Error::<T>::CollectibleNotForSale);
+            ensure_none(origin)?;
+            Self::ensure_migrated()?;
+            ensure!(Self::listing_expired(collectible_id), Error::<T, I>::ListingNotExpired);
+            let mut collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
             collectible.price = None;
-            CollectibleMap::<T>::insert(collectible_id, collectible);
+            CollectibleMap::<T, I>::insert(collectible_id, collectible);
+            Self::clear_price_unit(collectible_id);
+            Self::touch(collectible_id);
             Self::deposit_event(Event::NotLongerOnSale { collectible: collectible_id });
             Ok(())
         }
 
-        #[pallet::weight(0)] 
-        pub fn buy(
+        /// Approve transferring a co-owned collectible to `to`, dropping the approvals for any other destination. The
+        /// transfer happens once the approvals cover more than the threshold of the shares, or all of them, ending the
+        /// co-ownership. The co-owner whose approval completes it pays the transfer tax and royalties
+        #[pallet::call_index(62)]
+        #[pallet::weight(T::WeightInfo::approve_disposal())]
+        pub fn approve_disposal(
             origin: OriginFor<T>,
             collectible_id: [u8; 16],
-            offered_price: BalanceOf<T>
+            to: T::AccountId
         ) -> DispatchResult{
-            let buyer = ensure_signed(origin)?; // Ensure that the buyer signed the transaction
-            let collectible = CollectibleMap::<T>::get(&collectible_id).ok_or(Error::<T>::NoCollectible)?;
-            ensure!(collectible.price.is_some(), Error::<T>::CollectibleNotForSale);
-            ensure!(offered_price >= collectible.price.unwrap(), Error::<T>::OfferedPriceTooLow);
-            Self::do_buy(collectible_id, buyer, offered_price)?;
+            let co_owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let co_ownership = CoOwned::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NotCoOwned)?;
+            ensure!(co_ownership.owners.iter().any(|(owner, _)| *owner == co_owner), Error::<T, I>::NotCoOwner);
+
+            let mut approvers = match DisposalApprovals::<T, I>::get(&collectible_id){
+                Some((proposed, approvers)) if proposed == to => approvers,
+                _ => BoundedVec::default(),
+            };
+            ensure!(!approvers.contains(&co_owner), Error::<T, I>::AlreadyApproved);
+            // Co-owners are distinct, so there's room for each of them
+            approvers.try_push(co_owner.clone()).map_err(|_| Error::<T, I>::InvalidCoOwners)?;
+            Self::deposit_event(Event::DisposalApproved {
+                collectible: collectible_id,
+                co_owner: co_owner.clone(),
+                to: to.clone()
+            });
+
+            if Self::approved_by(&co_ownership, &approvers){
+                DisposalApprovals::<T, I>::remove(collectible_id);
+                let collectible = CollectibleMap::<T, I>::get(&collectible_id).ok_or(Error::<T, I>::NoCollectible)?;
+                // The co-ownership account holds no funds of its own
+                Self::charge_transfer_tax(&collectible, &co_owner)?;
+                Self::enforce_royalty_paid_by(collectible_id, &collectible.owner, &to, &co_owner)?;
+                Self::do_transfer(collectible_id, to)?;
+            }
+            else{
+                DisposalApprovals::<T, I>::insert(collectible_id, (to, approvers));
+            }
             Ok(())
         }
     }
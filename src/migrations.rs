@@ -0,0 +1,785 @@
+//! Storage migrations of the collectibles pallet.
+//! Each module upgrades the storage from the previous version and is a no-op on any other version.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    storage::{unhashed, StoragePrefixedMap},
+    storage_alias,
+    traits::{OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+use sp_std::vec::Vec;
+
+/// Version 1 records the creator of each collectible, counts them with a `CountedStorageMap`, indexes ownership with a
+/// double map and uses `Blake2_128Concat` for every user-influenced key.
+pub mod v1 {
+    use super::*;
+
+    /// The collectible as stored in version 0
+    #[derive(Encode, Decode)]
+    pub struct OldCollectible<T: Config<I>, I: 'static = ()> {
+        pub unique_id: [u8; 16],
+        pub price: Option<BalanceOf<T, I>>,
+        pub color: Color,
+        pub owner: T::AccountId,
+    }
+
+    #[storage_alias]
+    pub(super) type CollectiblesCount<T: Config<I>, I: 'static> = StorageValue<Pallet<T, I>, u64, ValueQuery>;
+
+    #[storage_alias]
+    pub(super) type CollectibleMap<T: Config<I>, I: 'static> = StorageMap<Pallet<T, I>, Twox64Concat, [u8; 16], OldCollectible<T, I>>;
+
+    // The bound of the old BoundedVec doesn't affect its encoding
+    #[storage_alias]
+    pub(super) type OwnerOfCollectibles<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Twox64Concat,
+        <T as frame_system::Config>::AccountId,
+        Vec<[u8; 16]>,
+        ValueQuery,
+    >;
+
+    pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // The old and new entries live under the same prefixes, so the old ones are drained before writing anything
+            let collectibles: Vec<([u8; 16], OldCollectible<T, I>)> = CollectibleMap::<T, I>::drain().collect();
+            let owners: Vec<(T::AccountId, Vec<[u8; 16]>)> = OwnerOfCollectibles::<T, I>::drain().collect();
+            CollectiblesCount::<T, I>::kill();
+
+            let reads = (collectibles.len() + owners.len()) as u64 + 1;
+            let mut writes = reads + 1;
+
+            for (collectible_id, old) in collectibles {
+                // Inserting into the counted map also sets up its counter
+                crate::pallet::CollectibleMap::<T, I>::insert(collectible_id, Collectible::<T, I> {
+                    unique_id: old.unique_id,
+                    price: old.price,
+                    color: old.color,
+                    // The creator wasn't recorded so far, the current owner is the best approximation
+                    creator: old.owner.clone(),
+                    owner: old.owner,
+                });
+                writes += 2;
+            }
+
+            let mut holders = 0u32;
+            for (owner, owned) in owners {
+                if owned.is_empty() {
+                    continue;
+                }
+                holders += 1;
+                crate::pallet::OwnedCount::<T, I>::insert(&owner, owned.len() as u32);
+                writes += 1;
+                if T::EnumerateOwnership::get() {
+                    writes += owned.len() as u64;
+                    for collectible_id in owned {
+                        crate::pallet::OwnerOfCollectibles::<T, I>::insert(&owner, collectible_id, ());
+                    }
+                }
+            }
+            crate::pallet::HoldersCount::<T, I>::put(holders);
+
+            StorageVersion::new(1).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 0 {
+                return Ok(Vec::new());
+            }
+            let collectibles = CollectibleMap::<T, I>::iter_keys().count() as u32;
+            Ok(collectibles.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let collectibles: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 1, "The storage version wasn't updated");
+            ensure!(
+                crate::pallet::CollectibleMap::<T, I>::count() == collectibles,
+                "Some collectibles were lost during the migration"
+            );
+            let indexed: u32 = crate::pallet::OwnedCount::<T, I>::iter_values().sum();
+            ensure!(indexed == collectibles, "The ownership index doesn't match the collectibles");
+            Ok(())
+        }
+    }
+
+    /// Multi-block version of `MigrateToV1`, for chains whose collectibles don't fit in a single block.
+    /// The upgrade only kicks the migration off; the pallet then migrates as many entries as possible in every
+    /// `on_idle`, and rejects calls and holds back the hooks reading collectibles until it's done. As the storage only
+    /// reaches version 1 once it's done, `v2::MigrateToV2` has to be shipped in a later upgrade.
+    pub struct MigrateToV1Stepped<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1Stepped<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 0 || crate::pallet::OngoingMigration::<T, I>::exists() {
+                return T::DbWeight::get().reads(2);
+            }
+            CollectiblesCount::<T, I>::kill();
+            crate::pallet::OngoingMigration::<T, I>::put(MigrationStage::Collectibles(None));
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 0 || crate::pallet::OngoingMigration::<T, I>::exists() {
+                return Ok(Vec::new());
+            }
+            let collectibles = CollectibleMap::<T, I>::iter_keys().count() as u32;
+            Ok(collectibles.encode())
+        }
+
+        // Only the kick-off can be checked here, the entries are migrated by `step` in the following blocks
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let collectibles: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 0, "The storage version was updated before migrating");
+            ensure!(
+                crate::pallet::OngoingMigration::<T, I>::get() == Some(MigrationStage::Collectibles(None)),
+                "The migration wasn't started from the first collectible"
+            );
+            ensure!(!CollectiblesCount::<T, I>::exists(), "The old collectibles count wasn't removed");
+            ensure!(
+                CollectibleMap::<T, I>::iter_keys().count() as u32 == collectibles,
+                "Some collectibles were touched before the migration started"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrate as many entries as `remaining_weight` allows, returning the weight consumed.
+    /// At least one entry is migrated in every step, even over `remaining_weight`, so an entry heavier than what's
+    /// left in any block can't stall the migration.
+    /// Old and new entries share their prefixes; the old ones are told apart by the length of their keys.
+    pub fn step<T: Config<I>, I: 'static>(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut consumed = db_weight.reads(1);
+        let Some(mut stage) = crate::pallet::OngoingMigration::<T, I>::get() else {
+            return consumed;
+        };
+        let mut progressed = false;
+
+        loop {
+            let (prefix, cursor) = match &stage {
+                MigrationStage::Collectibles(cursor) => (CollectibleMap::<T, I>::final_prefix(), cursor.clone()),
+                MigrationStage::Owners(cursor) => (OwnerOfCollectibles::<T, I>::final_prefix(), cursor.clone()),
+            };
+            let mut previous_key = cursor.map(BoundedVec::into_inner).unwrap_or_else(|| prefix.to_vec());
+
+            loop {
+                // Keep room to store the cursor
+                let budget = if progressed {
+                    remaining_weight.saturating_sub(consumed).saturating_sub(db_weight.reads_writes(1, 1))
+                } else {
+                    Weight::MAX
+                };
+                let Some(key) = sp_io::storage::next_key(&previous_key).filter(|key| key.starts_with(&prefix)) else {
+                    consumed.saturating_accrue(db_weight.reads(1));
+                    break;
+                };
+                let migrated = match stage {
+                    MigrationStage::Collectibles(_) => migrate_collectible::<T, I>(&key, prefix.len(), budget),
+                    MigrationStage::Owners(_) => migrate_owner::<T, I>(&key, prefix.len(), budget),
+                };
+                match migrated {
+                    Ok(weight) => {
+                        consumed.saturating_accrue(db_weight.reads(1).saturating_add(weight));
+                        previous_key = key;
+                        progressed = true;
+                    }
+                    Err(weight) => {
+                        // Out of weight, resume after the last migrated key in the next block
+                        consumed.saturating_accrue(db_weight.reads_writes(1, 1).saturating_add(weight));
+                        let cursor = BoundedVec::try_from(previous_key).ok();
+                        stage = match stage {
+                            MigrationStage::Collectibles(_) => MigrationStage::Collectibles(cursor),
+                            MigrationStage::Owners(_) => MigrationStage::Owners(cursor),
+                        };
+                        crate::pallet::OngoingMigration::<T, I>::put(stage);
+                        return consumed;
+                    }
+                }
+            }
+
+            stage = match stage {
+                MigrationStage::Collectibles(_) => MigrationStage::Owners(None),
+                MigrationStage::Owners(_) => {
+                    crate::pallet::OngoingMigration::<T, I>::kill();
+                    StorageVersion::new(1).put::<Pallet<T, I>>();
+                    return consumed.saturating_add(db_weight.writes(2));
+                }
+            };
+        }
+    }
+
+    // Migrate the `CollectibleMap` entry at `key` if it has the old layout. `Err` means it didn't fit in `budget`
+    fn migrate_collectible<T: Config<I>, I: 'static>(key: &[u8], prefix_len: usize, budget: Weight) -> Result<Weight, Weight> {
+        // Twox64Concat hash plus the id
+        if key.len() != prefix_len + 8 + 16 {
+            return Ok(Weight::zero());
+        }
+        let weight = T::DbWeight::get().reads_writes(1, 3);
+        if weight.any_gt(budget) {
+            return Err(Weight::zero());
+        }
+        let mut collectible_id = [0u8; 16];
+        collectible_id.copy_from_slice(&key[prefix_len + 8..]);
+        if let Some(old) = unhashed::get::<OldCollectible<T, I>>(key) {
+            crate::pallet::CollectibleMap::<T, I>::insert(collectible_id, Collectible::<T, I> {
+                unique_id: old.unique_id,
+                price: old.price,
+                color: old.color,
+                // The creator wasn't recorded so far, the current owner is the best approximation
+                creator: old.owner.clone(),
+                owner: old.owner,
+            });
+        }
+        unhashed::kill(key);
+        Ok(weight)
+    }
+
+    // Migrate the `OwnerOfCollectibles` entry at `key` if it has the old layout. `Err` means it didn't fit in `budget`
+    fn migrate_owner<T: Config<I>, I: 'static>(key: &[u8], prefix_len: usize, budget: Weight) -> Result<Weight, Weight> {
+        let db_weight = T::DbWeight::get();
+        // Twox64Concat hash plus exactly one account
+        let Some(mut raw_owner) = key.get(prefix_len + 8..) else {
+            return Ok(Weight::zero());
+        };
+        let owner = match T::AccountId::decode(&mut raw_owner) {
+            Ok(owner) if raw_owner.is_empty() => owner,
+            _ => return Ok(Weight::zero()),
+        };
+        let owned = unhashed::get::<Vec<[u8; 16]>>(key).unwrap_or_default();
+        // Remove the old entry and write the count, the holders and the index
+        let weight = db_weight.reads_writes(2, 3 + owned.len() as u64);
+        if weight.any_gt(budget) {
+            return Err(db_weight.reads(1));
+        }
+        unhashed::kill(key);
+        if !owned.is_empty() {
+            crate::pallet::OwnedCount::<T, I>::insert(&owner, owned.len() as u32);
+            crate::pallet::HoldersCount::<T, I>::mutate(|holders| *holders = holders.saturating_add(1));
+            if T::EnumerateOwnership::get() {
+                for collectible_id in owned {
+                    crate::pallet::OwnerOfCollectibles::<T, I>::insert(&owner, collectible_id, ());
+                }
+            }
+        }
+        Ok(weight)
+    }
+}
+
+/// Version 2 removes the owned count of accounts that no longer hold any collectible instead of storing a zero.
+pub mod v2 {
+    use super::*;
+
+    pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+            let empty: Vec<T::AccountId> = crate::pallet::OwnedCount::<T, I>::iter()
+                .inspect(|_| reads += 1)
+                .filter_map(|(owner, owned)| (owned == 0).then_some(owner))
+                .collect();
+            for owner in empty {
+                crate::pallet::OwnedCount::<T, I>::remove(owner);
+                writes += 1;
+            }
+
+            StorageVersion::new(2).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 1 {
+                return Ok(Vec::new());
+            }
+            let holders = crate::pallet::OwnedCount::<T, I>::iter_values().filter(|owned| *owned > 0).count() as u32;
+            Ok(holders.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let holders: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 2, "The storage version wasn't updated");
+            ensure!(
+                crate::pallet::OwnedCount::<T, I>::iter_values().all(|owned| owned > 0),
+                "An empty owned count survived the migration"
+            );
+            ensure!(
+                crate::pallet::OwnedCount::<T, I>::iter_keys().count() as u32 == holders,
+                "Some holders were lost during the migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 3 counts and indexes the collectibles by color.
+pub mod v3 {
+    use super::*;
+
+    pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+            for (collectible_id, collectible) in crate::pallet::CollectibleMap::<T, I>::iter() {
+                crate::pallet::ColorSupply::<T, I>::mutate(collectible.color, |supply| *supply = supply.saturating_add(1));
+                crate::pallet::CollectiblesByColor::<T, I>::insert(collectible.color, collectible_id, ());
+                reads += 2;
+                writes += 2;
+            }
+
+            StorageVersion::new(3).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 2 {
+                return Ok(Vec::new());
+            }
+            Ok(crate::pallet::CollectibleMap::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let collectibles: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 3, "The storage version wasn't updated");
+            ensure!(
+                crate::pallet::ColorSupply::<T, I>::iter_values().fold(0u32, |total, supply| total.saturating_add(supply)) == collectibles,
+                "Some collectibles weren't counted"
+            );
+            ensure!(
+                crate::pallet::CollectiblesByColor::<T, I>::iter_keys().count() as u32 == collectibles,
+                "Some collectibles weren't indexed"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 4 records what holds each collectible locked, so only that can unlock it.
+pub mod v4 {
+    use super::*;
+    use crate::pallet::{
+        Airdrops, CallOptions, Escrows, Exported, Fractionalized, Gifts, Loans, LockReason, Parked, Rentals,
+        ScheduledTransfers, Stakes,
+    };
+    use frame_support::traits::LockIdentifier;
+
+    /// The lock identifier given to collectibles other pallets locked before version 4. They unlock them with it
+    pub const LEGACY_LOCK: LockIdentifier = *b"legacylk";
+
+    // The pallet's features lock the collectibles they keep an entry for, anything else was locked by another pallet
+    fn reason_of<T: Config<I>, I: 'static>(collectible_id: &[u8; 16]) -> LockReason {
+        if Fractionalized::<T, I>::contains_key(collectible_id) {
+            LockReason::Fractionalized
+        } else if Loans::<T, I>::contains_key(collectible_id) {
+            LockReason::Loan
+        } else if Rentals::<T, I>::contains_key(collectible_id) {
+            LockReason::Rental
+        } else if Stakes::<T, I>::contains_key(collectible_id) {
+            LockReason::Staked
+        } else if Parked::<T, I>::contains_key(collectible_id) {
+            LockReason::Parked
+        } else if Exported::<T, I>::contains_key(collectible_id) {
+            LockReason::Exported
+        } else if ScheduledTransfers::<T, I>::contains_key(collectible_id) {
+            LockReason::ScheduledTransfer
+        } else if CallOptions::<T, I>::contains_key(collectible_id) {
+            LockReason::CallOption
+        } else if Escrows::<T, I>::contains_key(collectible_id) {
+            LockReason::Escrow
+        } else if Gifts::<T, I>::contains_key(collectible_id) {
+            LockReason::Gift
+        } else if Airdrops::<T, I>::contains_key(collectible_id) {
+            LockReason::Airdrop
+        } else {
+            LockReason::External(LEGACY_LOCK)
+        }
+    }
+
+    pub struct MigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 3 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+            crate::pallet::LockedCollectibles::<T, I>::translate::<(), _>(|collectible_id, ()| {
+                // The lock itself and up to every feature's entry
+                reads += 12;
+                writes += 1;
+                Some(reason_of::<T, I>(&collectible_id))
+            });
+
+            StorageVersion::new(4).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 3 {
+                return Ok(Vec::new());
+            }
+            Ok((crate::pallet::LockedCollectibles::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let locked: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 4, "The storage version wasn't updated");
+            ensure!(
+                crate::pallet::LockedCollectibles::<T, I>::iter_values().count() as u32 == locked,
+                "Some locks were lost during the migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 5 indexes listing expiries and buyouts by the block they're due in, so the offchain worker only reads the
+/// due ones. Those due already are moved to expire or end in the block before the upgrade, so they're due in it.
+pub mod v5 {
+    use super::*;
+    use crate::pallet::{Buyout, Buyouts, EndingBuyouts, ExpiringListings, ListingExpiries};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::traits::{One, Saturating};
+
+    pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 4 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // Still expired or over after the move, as they were
+            let last = frame_system::Pallet::<T>::block_number().saturating_sub(One::one());
+            let mut reads = 2u64;
+            let mut writes = 1u64;
+            ListingExpiries::<T, I>::translate::<BlockNumberFor<T>, _>(|collectible_id, until| {
+                reads += 1;
+                writes += 2;
+                let until = until.max(last);
+                ExpiringListings::<T, I>::insert(until.saturating_add(One::one()), collectible_id, ());
+                Some(until)
+            });
+            Buyouts::<T, I>::translate::<Buyout<T, I>, _>(|collectible_id, buyout| {
+                reads += 1;
+                writes += 2;
+                let end = buyout.end.max(last);
+                EndingBuyouts::<T, I>::insert(end.saturating_add(One::one()), collectible_id, ());
+                Some(Buyout { end, ..buyout })
+            });
+
+            StorageVersion::new(5).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 4 {
+                return Ok(Vec::new());
+            }
+            let listings = ListingExpiries::<T, I>::iter_keys().count() as u32;
+            let buyouts = Buyouts::<T, I>::iter_keys().count() as u32;
+            Ok((listings, buyouts).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let (listings, buyouts): (u32, u32) = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 5, "The storage version wasn't updated");
+            ensure!(ExpiringListings::<T, I>::iter_keys().count() as u32 == listings, "Some listing expiries weren't indexed");
+            ensure!(EndingBuyouts::<T, I>::iter_keys().count() as u32 == buyouts, "Some buyouts weren't indexed");
+            Ok(())
+        }
+    }
+}
+
+/// Version 6 queues every rental expiry under its own key, so any amount of rentals can expire in the same block.
+pub mod v6 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    #[storage_alias]
+    pub(super) type RentalExpiries<T: Config<I>, I: 'static> =
+        StorageMap<Pallet<T, I>, Twox64Concat, BlockNumberFor<T>, Vec<[u8; 16]>, ValueQuery>;
+
+    pub struct MigrateToV6<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T, I>>() != 5 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // The old and new entries live under the same prefix, so the old ones are drained before writing anything
+            let queues: Vec<(BlockNumberFor<T>, Vec<[u8; 16]>)> = RentalExpiries::<T, I>::drain().collect();
+            let mut reads = queues.len() as u64 + 1;
+            let mut writes = reads + 1;
+            let mut queued = 0u32;
+            for (block, expiring) in queues {
+                for collectible_id in expiring {
+                    reads += 1;
+                    // A rental closed early and rented again may have been queued twice in the same block
+                    if crate::pallet::RentalExpiries::<T, I>::contains_key(block, collectible_id) {
+                        continue;
+                    }
+                    crate::pallet::RentalExpiries::<T, I>::insert(block, collectible_id, ());
+                    queued += 1;
+                    writes += 1;
+                }
+            }
+            crate::pallet::QueuedRentalExpiries::<T, I>::put(queued);
+
+            StorageVersion::new(6).put::<Pallet<T, I>>();
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            if StorageVersion::get::<Pallet<T, I>>() != 5 {
+                return Ok(Vec::new());
+            }
+            let queued = RentalExpiries::<T, I>::iter_values().map(|expiring| expiring.len() as u32).sum::<u32>();
+            Ok(queued.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let queued: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(StorageVersion::get::<Pallet<T, I>>() == 6, "The storage version wasn't updated");
+            let migrated = crate::pallet::RentalExpiries::<T, I>::iter_keys().count() as u32;
+            ensure!(migrated <= queued, "The migration queued more rental expiries than there were");
+            ensure!(
+                crate::pallet::QueuedRentalExpiries::<T, I>::get() == migrated,
+                "The queued rental expiries count doesn't match the queue"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Rebuild of the ownership index, for upgrades switching `Config::EnumerateOwnership`.
+pub mod ownership_index {
+    use super::*;
+    use crate::pallet::{CollectibleMap, OwnerOfCollectibles};
+
+    /// Indexes every collectible under its owner when `EnumerateOwnership` is on, and removes the index when it's off.
+    /// It has to be shipped in the upgrade switching `EnumerateOwnership`, as only collectibles that change hands
+    /// afterwards would be indexed otherwise. An index that already has entries is left alone, so running it twice is
+    /// harmless, but every collectible is indexed in the upgrade block, so it only suits chains whose collectibles fit
+    /// in it.
+    pub struct RebuildOwnershipIndex<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for RebuildOwnershipIndex<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let indexed = OwnerOfCollectibles::<T, I>::iter_keys().next().is_some();
+            if !T::EnumerateOwnership::get() {
+                if !indexed {
+                    return T::DbWeight::get().reads(1);
+                }
+                let removed = OwnerOfCollectibles::<T, I>::clear(u32::MAX, None).unique as u64;
+                return T::DbWeight::get().reads_writes(1 + removed, removed);
+            }
+            if indexed {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+            for collectible in CollectibleMap::<T, I>::iter_values() {
+                reads += 1;
+                writes += 1;
+                OwnerOfCollectibles::<T, I>::insert(&collectible.owner, collectible.unique_id, ());
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let indexed = OwnerOfCollectibles::<T, I>::iter_keys().count() as u32;
+            if T::EnumerateOwnership::get() {
+                ensure!(indexed == CollectibleMap::<T, I>::count(), "Some collectibles weren't indexed under their owner");
+            } else {
+                ensure!(indexed == 0, "The ownership index is disabled but has entries");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// One-shot import of a `pallet_uniques` collection, for chains replacing uniques with this pallet.
+pub mod uniques {
+    use super::*;
+    use frame_support::{
+        storage::migration::{get_storage_value, storage_iter_with_suffix},
+        StorageHasher,
+    };
+
+    /// An item as stored by `pallet_uniques`
+    #[derive(Decode)]
+    #[allow(dead_code)]
+    struct ItemDetails<AccountId, DepositBalance> {
+        owner: AccountId,
+        approved: Option<AccountId>,
+        is_frozen: bool,
+        deposit: DepositBalance,
+    }
+
+    /// The collectible id an item of a `pallet_uniques` collection is imported as
+    pub fn collectible_id<CollectionId: Encode, ItemId: Encode>(collection: &CollectionId, item: &ItemId) -> [u8; 16] {
+        (collection, item).using_encoded(sp_io::hashing::blake2_128)
+    }
+
+    /// Imports every item of the `Collection` of the `pallet_uniques` instance named `UniquesName` in the runtime, with
+    /// its owner, approval and price, as the collectible given by `collectible_id`. Its creator is its current owner
+    /// and its color is derived from its id, like for minted collectibles.
+    /// Only prices open to any buyer are imported. The deposits stay reserved by `pallet_uniques` and frozen items are
+    /// imported transferable. Imported items are skipped, so running it twice is harmless, but the whole collection is
+    /// imported in the upgrade block, so it only suits collections that fit in it.
+    pub struct ImportFromUniques<T, UniquesName, Collection, CollectionId, ItemId, I = ()>(
+        PhantomData<(T, UniquesName, Collection, CollectionId, ItemId, I)>,
+    );
+
+    impl<T, UniquesName, Collection, CollectionId, ItemId, I> OnRuntimeUpgrade
+        for ImportFromUniques<T, UniquesName, Collection, CollectionId, ItemId, I>
+    where
+        T: Config<I>,
+        I: 'static,
+        UniquesName: Get<&'static str>,
+        Collection: Get<CollectionId>,
+        CollectionId: Encode,
+        ItemId: Encode + Decode,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let module = UniquesName::get().as_bytes();
+            let collection = Collection::get();
+            let collection_key = Blake2_128Concat::hash(&collection.encode());
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            let items = storage_iter_with_suffix::<ItemDetails<T::AccountId, BalanceOf<T, I>>>(
+                module,
+                b"Asset",
+                &collection_key,
+            );
+            for (item_key, details) in items {
+                reads += 2;
+                // Blake2_128Concat hash plus the item id
+                let Some(Ok(item)) = item_key.get(16..).map(|mut raw_item| ItemId::decode(&mut raw_item)) else {
+                    continue;
+                };
+                let collectible_id = collectible_id(&collection, &item);
+                if crate::pallet::CollectibleMap::<T, I>::contains_key(collectible_id) {
+                    continue;
+                }
+                let price = get_storage_value::<(BalanceOf<T, I>, Option<T::AccountId>)>(
+                    module,
+                    b"ItemPriceOf",
+                    &[&collection_key[..], &item_key[..]].concat(),
+                )
+                .and_then(|(price, buyer)| buyer.is_none().then_some(price));
+                reads += 3;
+                let imported = Pallet::<T, I>::import(Collectible::<T, I> {
+                    unique_id: collectible_id,
+                    price,
+                    color: if collectible_id[0] % 2 == 0 { Color::Red } else { Color::Yellow },
+                    owner: details.owner.clone(),
+                    creator: details.owner,
+                });
+                if imported.is_err() {
+                    frame_support::defensive!("The total supply can't overflow while importing a collection");
+                    continue;
+                }
+                writes += 6;
+                if let Some(approved) = details.approved {
+                    crate::pallet::Approvals::<T, I>::insert(collectible_id, approved);
+                    writes += 1;
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let collection = Collection::get();
+            let items = storage_iter_with_suffix::<ItemDetails<T::AccountId, BalanceOf<T, I>>>(
+                UniquesName::get().as_bytes(),
+                b"Asset",
+                &Blake2_128Concat::hash(&collection.encode()),
+            );
+            let mut pending = 0u32;
+            for (item_key, _) in items {
+                let item = item_key.get(16..).map(|mut raw_item| ItemId::decode(&mut raw_item));
+                if let Some(Ok(item)) = item {
+                    if !crate::pallet::CollectibleMap::<T, I>::contains_key(collectible_id(&collection, &item)) {
+                        pending += 1;
+                    }
+                }
+            }
+            Ok((crate::pallet::CollectibleMap::<T, I>::count(), pending).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (collectibles, pending): (u32, u32) =
+                Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(
+                crate::pallet::CollectibleMap::<T, I>::count() == collectibles.saturating_add(pending),
+                "Some items of the collection weren't imported"
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,1166 @@
+//! Weights for the collectibles pallet.
+//!
+//! These are estimates built from the storage accesses of each call, to be replaced by benchmarked values generated on
+//! reference hardware with the template in `.maintain`:
+//! `./target/release/node benchmark pallet --chain dev --pallet pallet_collectibles --extrinsic '*' --steps 50 --repeat 20
+//! --wasm-execution compiled --template .maintain/frame-weight-template.hbs --output src/weights.rs`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for the collectibles pallet.
+pub trait WeightInfo {
+	fn create_collectible() -> Weight;
+	fn destroy_collectible(a: u32, m: u32, ) -> Weight;
+	fn transfer(o: u32, ) -> Weight;
+	fn set_price() -> Weight;
+	fn remove_from_market() -> Weight;
+	fn buy(o: u32, ) -> Weight;
+	fn force_transfer() -> Weight;
+	fn force_destroy(a: u32, m: u32, ) -> Weight;
+	fn set_paused() -> Weight;
+	fn set_parameter() -> Weight;
+	fn report_stolen() -> Weight;
+	fn clear_stolen() -> Weight;
+	fn flag_item() -> Weight;
+	fn clear_flag() -> Weight;
+	fn verify_creator() -> Weight;
+	fn unverify_creator() -> Weight;
+	fn set_transfers_enabled() -> Weight;
+	fn set_price_in_asset() -> Weight;
+	fn buy_with_asset() -> Weight;
+	fn set_reference_price() -> Weight;
+	fn set_asset_quotes(q: u32, ) -> Weight;
+	fn fractionalize() -> Weight;
+	fn unify() -> Weight;
+	fn bid_buyout(b: u32, ) -> Weight;
+	fn settle_buyout() -> Weight;
+	fn redeem_shares() -> Weight;
+	fn create_backed_collectible() -> Weight;
+	fn request_loan() -> Weight;
+	fn cancel_loan() -> Weight;
+	fn fund_loan() -> Weight;
+	fn repay_loan() -> Weight;
+	fn claim_collateral() -> Weight;
+	fn list_for_rent() -> Weight;
+	fn unlist_for_rent() -> Weight;
+	fn rent() -> Weight;
+	fn end_rental() -> Weight;
+	fn offer_rent_to_own() -> Weight;
+	fn exercise_rent_to_own() -> Weight;
+	fn expire_rental() -> Weight;
+	fn rental_expiry_step() -> Weight;
+	fn start_rent_stream() -> Weight;
+	fn top_up_rent_stream() -> Weight;
+	fn stop_rent_stream() -> Weight;
+	fn stake() -> Weight;
+	fn claim_staking_rewards() -> Weight;
+	fn unstake() -> Weight;
+	fn deposit_revenue() -> Weight;
+	fn claim_revenue(c: u32, ) -> Weight;
+	fn set_charity_cut() -> Weight;
+	fn set_transfer_tax() -> Weight;
+	fn set_royalty() -> Weight;
+	fn set_royalty_enforcement() -> Weight;
+	fn set_royalty_exempt() -> Weight;
+	fn force_set_royalty() -> Weight;
+	fn remote_buy() -> Weight;
+	fn export_collectible() -> Weight;
+	fn import_collectible() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn import_collection(i: u32, a: u32, ) -> Weight;
+	fn schedule_transfer() -> Weight;
+	fn cancel_scheduled_transfer() -> Weight;
+	fn execute_scheduled_transfer() -> Weight;
+	fn set_manager() -> Weight;
+	fn share_ownership() -> Weight;
+	fn approve_disposal() -> Weight;
+	fn set_listing_expiry() -> Weight;
+	fn delist_expired() -> Weight;
+	fn claim_dev_collectible() -> Weight;
+	fn airdrop() -> Weight;
+	fn claim_airdrop() -> Weight;
+	fn cancel_airdrop() -> Weight;
+	fn transfer_pre_signed() -> Weight;
+	fn set_price_at() -> Weight;
+	fn set_declining_price() -> Weight;
+	fn set_relist_cooldown() -> Weight;
+	fn write_option() -> Weight;
+	fn buy_option() -> Weight;
+	fn exercise_option() -> Weight;
+	fn close_option() -> Weight;
+	fn buy_escrowed() -> Weight;
+	fn release_escrow() -> Weight;
+	fn dispute_escrow() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn gift() -> Weight;
+	fn claim_gift() -> Weight;
+	fn revert_gift() -> Weight;
+	fn tip_creator() -> Weight;
+	fn set_name() -> Weight;
+	fn clear_name() -> Weight;
+	fn set_note() -> Weight;
+	fn set_trading_paused() -> Weight;
+	fn set_listing_condition() -> Weight;
+	fn allow_holder() -> Weight;
+	fn disallow_holder() -> Weight;
+	fn set_reward_quota() -> Weight;
+	fn reward() -> Weight;
+	fn snapshot_holders(n: u32, ) -> Weight;
+	fn set_default_royalty() -> Weight;
+	fn set_royalty_range() -> Weight;
+}
+
+/// Weights for the collectibles pallet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_collectible() -> Weight {
+		Weight::from_parts(32_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	fn destroy_collectible(a: u32, m: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(27_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2_655).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_200, 1).saturating_mul(m.into()))
+	}
+	fn transfer(o: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 6_196)
+			.saturating_add(Weight::from_parts(9_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(27_u64))
+	}
+	fn set_price() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn remove_from_market() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn buy(o: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 6_196)
+			.saturating_add(Weight::from_parts(9_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+	}
+	fn force_transfer() -> Weight {
+		Weight::from_parts(34_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	fn force_destroy(a: u32, m: u32, ) -> Weight {
+		Weight::from_parts(28_000_000, 3_593)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2_655).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_200, 1).saturating_mul(m.into()))
+	}
+	fn set_paused() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_parameter() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn report_stolen() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn clear_stolen() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn flag_item() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn clear_flag() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn verify_creator() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unverify_creator() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_transfers_enabled() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_price_in_asset() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn buy_with_asset() -> Weight {
+		Weight::from_parts(75_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(31_u64))
+			.saturating_add(T::DbWeight::get().writes(28_u64))
+	}
+	fn set_reference_price() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn set_asset_quotes(q: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(q.into()))
+	}
+	fn fractionalize() -> Weight {
+		Weight::from_parts(45_000_000, 3_675)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn unify() -> Weight {
+		Weight::from_parts(70_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
+	fn bid_buyout(b: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3_675)
+			.saturating_add(Weight::from_parts(18_000_000, 2_603).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	fn settle_buyout() -> Weight {
+		Weight::from_parts(60_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	fn redeem_shares() -> Weight {
+		Weight::from_parts(45_000_000, 3_675)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn create_backed_collectible() -> Weight {
+		Weight::from_parts(55_000_000, 3_675)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	fn request_loan() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn cancel_loan() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn fund_loan() -> Weight {
+		Weight::from_parts(45_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn repay_loan() -> Weight {
+		Weight::from_parts(45_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn claim_collateral() -> Weight {
+		Weight::from_parts(60_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	fn list_for_rent() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unlist_for_rent() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn rent() -> Weight {
+		Weight::from_parts(50_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn end_rental() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn offer_rent_to_own() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn exercise_rent_to_own() -> Weight {
+		Weight::from_parts(75_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+	}
+	fn expire_rental() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn rental_expiry_step() -> Weight {
+		Weight::from_parts(8_000_000, 3_509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn start_rent_stream() -> Weight {
+		Weight::from_parts(80_000_000, 6_208)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn top_up_rent_stream() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn stop_rent_stream() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn stake() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn claim_staking_rewards() -> Weight {
+		Weight::from_parts(55_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn unstake() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn deposit_revenue() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn claim_revenue(c: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2_580).saturating_mul(c.into()))
+	}
+	fn set_charity_cut() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_transfer_tax() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_royalty() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_enforcement() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_exempt() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_set_royalty() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remote_buy() -> Weight {
+		Weight::from_parts(64_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(31_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+	fn export_collectible() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn import_collectible() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn transfer_from() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	fn import_collection(i: u32, a: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 4_160).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(i.into()).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into()).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into()).saturating_mul(a.into())))
+	}
+	fn schedule_transfer() -> Weight {
+		Weight::from_parts(36_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn cancel_scheduled_transfer() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn execute_scheduled_transfer() -> Weight {
+		Weight::from_parts(36_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	fn set_manager() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn share_ownership() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
+	fn approve_disposal() -> Weight {
+		Weight::from_parts(42_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(26_u64))
+	}
+	fn set_listing_expiry() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn delist_expired() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn claim_dev_collectible() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn airdrop() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn claim_airdrop() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	fn cancel_airdrop() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn transfer_pre_signed() -> Weight {
+		Weight::from_parts(70_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn set_price_at() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_declining_price() -> Weight {
+		Weight::from_parts(22_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn set_relist_cooldown() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn write_option() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn buy_option() -> Weight {
+		Weight::from_parts(30_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn exercise_option() -> Weight {
+		Weight::from_parts(64_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(31_u64))
+	}
+	fn close_option() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn buy_escrowed() -> Weight {
+		Weight::from_parts(66_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+	fn release_escrow() -> Weight {
+		Weight::from_parts(48_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn dispute_escrow() -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(30_u64))
+	}
+	fn gift() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn claim_gift() -> Weight {
+		Weight::from_parts(42_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	fn revert_gift() -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn tip_creator() -> Weight {
+		Weight::from_parts(30_000_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_name() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn clear_name() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn set_note() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_trading_paused() -> Weight {
+		Weight::from_parts(11_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_listing_condition() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn allow_holder() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn disallow_holder() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_reward_quota() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn reward() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn snapshot_holders(n: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 1_489)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(n.into()))
+	}
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(11_000_000, 1_594)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_range() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_collectible() -> Weight {
+		Weight::from_parts(32_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	fn destroy_collectible(a: u32, m: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2_655).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_200, 1).saturating_mul(m.into()))
+	}
+	fn transfer(o: u32, ) -> Weight {
+		Weight::from_parts(38_000_000, 6_196)
+			.saturating_add(Weight::from_parts(9_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+	}
+	fn set_price() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn remove_from_market() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn buy(o: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 6_196)
+			.saturating_add(Weight::from_parts(9_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+	}
+	fn force_transfer() -> Weight {
+		Weight::from_parts(34_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	fn force_destroy(a: u32, m: u32, ) -> Weight {
+		Weight::from_parts(28_000_000, 3_593)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2_655).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_200, 1).saturating_mul(m.into()))
+	}
+	fn set_paused() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_parameter() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn report_stolen() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn clear_stolen() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn flag_item() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn clear_flag() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn verify_creator() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unverify_creator() -> Weight {
+		Weight::from_parts(14_000_000, 3_513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_transfers_enabled() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_price_in_asset() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn buy_with_asset() -> Weight {
+		Weight::from_parts(75_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(31_u64))
+			.saturating_add(RocksDbWeight::get().writes(28_u64))
+	}
+	fn set_reference_price() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn set_asset_quotes(q: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(q.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(q.into()))
+	}
+	fn fractionalize() -> Weight {
+		Weight::from_parts(45_000_000, 3_675)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn unify() -> Weight {
+		Weight::from_parts(70_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
+	fn bid_buyout(b: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3_675)
+			.saturating_add(Weight::from_parts(18_000_000, 2_603).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	fn settle_buyout() -> Weight {
+		Weight::from_parts(60_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	fn redeem_shares() -> Weight {
+		Weight::from_parts(45_000_000, 3_675)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn create_backed_collectible() -> Weight {
+		Weight::from_parts(55_000_000, 3_675)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	fn request_loan() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn cancel_loan() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn fund_loan() -> Weight {
+		Weight::from_parts(45_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn repay_loan() -> Weight {
+		Weight::from_parts(45_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn claim_collateral() -> Weight {
+		Weight::from_parts(60_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	fn list_for_rent() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unlist_for_rent() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn rent() -> Weight {
+		Weight::from_parts(50_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn end_rental() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn offer_rent_to_own() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn exercise_rent_to_own() -> Weight {
+		Weight::from_parts(75_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+	}
+	fn expire_rental() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn rental_expiry_step() -> Weight {
+		Weight::from_parts(8_000_000, 3_509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn start_rent_stream() -> Weight {
+		Weight::from_parts(80_000_000, 6_208)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn top_up_rent_stream() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn stop_rent_stream() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn stake() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn claim_staking_rewards() -> Weight {
+		Weight::from_parts(55_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn unstake() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn deposit_revenue() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn claim_revenue(c: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2_580).saturating_mul(c.into()))
+	}
+	fn set_charity_cut() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_transfer_tax() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_royalty() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_enforcement() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_exempt() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn force_set_royalty() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remote_buy() -> Weight {
+		Weight::from_parts(64_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(31_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+	fn export_collectible() -> Weight {
+		Weight::from_parts(25_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn import_collectible() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	fn approve() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn transfer_from() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	fn import_collection(i: u32, a: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 4_160).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(i.into()).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(i.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into()).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(i.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(i.into()).saturating_mul(a.into())))
+	}
+	fn schedule_transfer() -> Weight {
+		Weight::from_parts(36_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn cancel_scheduled_transfer() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn execute_scheduled_transfer() -> Weight {
+		Weight::from_parts(36_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	fn set_manager() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn share_ownership() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
+	fn approve_disposal() -> Weight {
+		Weight::from_parts(42_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(26_u64))
+	}
+	fn set_listing_expiry() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn delist_expired() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn claim_dev_collectible() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn airdrop() -> Weight {
+		Weight::from_parts(18_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn claim_airdrop() -> Weight {
+		Weight::from_parts(40_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	fn cancel_airdrop() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn transfer_pre_signed() -> Weight {
+		Weight::from_parts(70_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn set_price_at() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_declining_price() -> Weight {
+		Weight::from_parts(22_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn set_relist_cooldown() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn write_option() -> Weight {
+		Weight::from_parts(19_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn buy_option() -> Weight {
+		Weight::from_parts(30_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn exercise_option() -> Weight {
+		Weight::from_parts(64_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(31_u64))
+	}
+	fn close_option() -> Weight {
+		Weight::from_parts(15_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn buy_escrowed() -> Weight {
+		Weight::from_parts(66_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+	fn release_escrow() -> Weight {
+		Weight::from_parts(48_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	fn dispute_escrow() -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(60_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(30_u64))
+	}
+	fn gift() -> Weight {
+		Weight::from_parts(20_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn claim_gift() -> Weight {
+		Weight::from_parts(42_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	fn revert_gift() -> Weight {
+		Weight::from_parts(12_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn tip_creator() -> Weight {
+		Weight::from_parts(30_000_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_name() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn clear_name() -> Weight {
+		Weight::from_parts(40_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_note() -> Weight {
+		Weight::from_parts(45_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_trading_paused() -> Weight {
+		Weight::from_parts(11_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_listing_condition() -> Weight {
+		Weight::from_parts(14_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn allow_holder() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn disallow_holder() -> Weight {
+		Weight::from_parts(16_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_reward_quota() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn reward() -> Weight {
+		Weight::from_parts(30_000_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn snapshot_holders(n: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 1_489)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(n.into()))
+	}
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(11_000_000, 1_594)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_royalty_range() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}